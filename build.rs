@@ -0,0 +1,46 @@
+//! Generates the registry of scaffolded days that is compiled into the `all` binary.
+//! Every `src/bin/DD.rs` is included as a module, so `all` can run the days in-process.
+//! The main binary does not include it, a day that does not compile must not break the other commands.
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut days: Vec<u8> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let day = name.strip_suffix(".rs")?;
+            if day.len() != 2 {
+                return None;
+            }
+            day.parse().ok()
+        })
+        .filter(|day| (1..=25).contains(day))
+        .collect();
+    days.sort_unstable();
+
+    let mut registry = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day:02}.rs"));
+        writeln!(registry, "#[path = {:?}]", path.to_string_lossy()).unwrap();
+        writeln!(registry, "mod day_{day:02};").unwrap();
+    }
+
+    registry.push_str("\npub const SOLUTIONS: &[advent_of_code::template::runner::DaySolution] = &[\n");
+    for day in &days {
+        writeln!(
+            registry,
+            "    advent_of_code::template::runner::DaySolution {{ day: advent_of_code::day!({day}), run: day_{day:02}::run_day }},"
+        )
+        .unwrap();
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry).unwrap();
+}
@@ -0,0 +1,39 @@
+//! The `all` binary, which runs every scaffolded day in-process. It is started by `cargo all` with
+//! the resolved arguments, see [`all::AllOptions`].
+use std::process;
+
+use advent_of_code::template::commands::all::{self, AllOptions};
+
+/// Every scaffolded day, generated by `build.rs` from the files in `src/bin`.
+mod registry {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+
+    match all::parse_worker(&mut args) {
+        Ok(Some((day, options))) => {
+            all::run_worker(registry::SOLUTIONS, day, &options);
+            return;
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    }
+
+    let options = AllOptions::from_args(&mut args).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        eprintln!("Error: unknown argument(s): {remaining:?}");
+        process::exit(1);
+    }
+
+    all::run(registry::SOLUTIONS, &options);
+}
@@ -3,17 +3,23 @@ use std::cmp::Ordering;
 advent_of_code::solution!(2);
 
 fn parse(input: &str) -> Vec<Vec<i32>> {
-    input.lines()
-         .map(|line| line.split_whitespace()
-            .map(|s| s.parse::<i32>().unwrap())
-            .collect::<Vec<i32>>())
-         .collect()
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|s| s.parse::<i32>().unwrap())
+                .collect::<Vec<i32>>()
+        })
+        .collect()
 }
 
 fn safe_report(report: Vec<i32>) -> u32 {
     let direction: Ordering = report[1].cmp(&report[0]);
     for levels in report.windows(2) {
-        if levels[0].abs_diff(levels[1]) > 3 || levels[0] == levels[1] || levels[1].cmp(&levels[0]) != direction {
+        if levels[0].abs_diff(levels[1]) > 3
+            || levels[0] == levels[1]
+            || levels[1].cmp(&levels[0]) != direction
+        {
             return 0;
         }
     }
@@ -21,13 +27,13 @@ fn safe_report(report: Vec<i32>) -> u32 {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    Some(parse(input).into_iter()
-        .map(|report| safe_report(report))
-        .sum())
+    Some(parse(input).into_iter().map(safe_report).sum())
 }
 
 fn super_safe_report(report: Vec<i32>) -> u32 {
-    if safe_report(report.clone()) == 1 { return 1; }
+    if safe_report(report.clone()) == 1 {
+        return 1;
+    }
     for i in 0..report.len() {
         let mut new_report = report.clone();
         new_report.remove(i);
@@ -39,9 +45,7 @@ fn super_safe_report(report: Vec<i32>) -> u32 {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    Some(parse(input).into_iter()
-        .map(|report| super_safe_report(report))
-        .sum())
+    Some(parse(input).into_iter().map(super_safe_report).sum())
 }
 
 #[cfg(test)]
@@ -1,11 +1,10 @@
 advent_of_code::solution!(4);
 
 fn parse(input: &str) -> Vec<Vec<char>> {
-        input.lines()
-        .map(|line| line.chars().collect() )
-        .collect()
+    input.lines().map(|line| line.chars().collect()).collect()
 }
 
+#[allow(clippy::needless_range_loop)]
 pub fn part_one(input: &str) -> Option<u32> {
     let grid: Vec<Vec<char>> = parse(input);
     let n: usize = grid.len();
@@ -20,8 +19,12 @@ pub fn part_one(input: &str) -> Option<u32> {
     // Horizontal
     for line in grid.iter() {
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
 
@@ -32,30 +35,42 @@ pub fn part_one(input: &str) -> Option<u32> {
             line.push(grid[i][j]);
         }
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
-    
+
     // Diagonal \
     for j in 0..m {
         let mut line = Vec::new();
         for i in 0..n - j {
-            line.push(grid[i][j+i]);
+            line.push(grid[i][j + i]);
         }
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
     for i in 1..n {
         let mut line = Vec::new();
         for j in 0..m - i {
-            line.push(grid[i+j][j]);
+            line.push(grid[i + j][j]);
         }
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
 
@@ -66,18 +81,26 @@ pub fn part_one(input: &str) -> Option<u32> {
             line.push(grid[i][m - 1 - j - i]);
         }
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
     for i in 1..n {
         let mut line = Vec::new();
         for j in 0..m - i {
-            line.push(grid[i+j][m - 1 - j]);
+            line.push(grid[i + j][m - 1 - j]);
         }
         for word in line.windows(key_word.len()) {
-            if word == key_word { count += 1; }
-            if word == rev_word { count += 1; }
+            if word == key_word {
+                count += 1;
+            }
+            if word == rev_word {
+                count += 1;
+            }
         }
     }
 
@@ -93,13 +116,20 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     for i in 1..n - 1 {
         for j in 1..m - 1 {
-            if grid[i][j] != 'A' { continue; }
-            count += match (grid[i-1][j-1], grid[i+1][j+1], grid[i-1][j+1], grid[i+1][j-1]) {
-              ('M', 'S', 'M', 'S') => 1,
-              ('M', 'S', 'S', 'M') => 1,
-              ('S', 'M', 'M', 'S') => 1,
-              ('S', 'M', 'S', 'M') => 1,
-              _ => 0,
+            if grid[i][j] != 'A' {
+                continue;
+            }
+            count += match (
+                grid[i - 1][j - 1],
+                grid[i + 1][j + 1],
+                grid[i - 1][j + 1],
+                grid[i + 1][j - 1],
+            ) {
+                ('M', 'S', 'M', 'S') => 1,
+                ('M', 'S', 'S', 'M') => 1,
+                ('S', 'M', 'M', 'S') => 1,
+                ('S', 'M', 'S', 'M') => 1,
+                _ => 0,
             };
         }
     }
@@ -19,9 +19,9 @@ struct Guard {
 impl Direction {
     fn rotate(self) -> Self {
         match self {
-            Direction::Up   => Direction::Right,
+            Direction::Up => Direction::Right,
             Direction::Down => Direction::Left,
-            Direction::Right=> Direction::Down,
+            Direction::Right => Direction::Down,
             Direction::Left => Direction::Up,
         }
     }
@@ -34,51 +34,66 @@ impl Guard {
             'v' => Direction::Down,
             '>' => Direction::Right,
             '<' => Direction::Left,
-            _ => { return None; }
+            _ => {
+                return None;
+            }
         };
-        Some(Guard{ pos, dir })
+        Some(Guard { pos, dir })
     }
 
-    fn next_pos(self, map: &Vec<Vec<bool>>) -> Option<Self> {
+    fn next_pos(self, map: &[Vec<bool>]) -> Option<Self> {
         let next_pos: (i32, i32) = match self.dir {
-            Direction::Up   => ( self.pos.0 as i32 - 1, self.pos.1 as i32 ),
-            Direction::Down => ( self.pos.0 as i32 + 1, self.pos.1 as i32 ),
-            Direction::Right=> ( self.pos.0 as i32, self.pos.1 as i32 + 1 ),
-            Direction::Left => ( self.pos.0 as i32, self.pos.1 as i32 - 1 ),
+            Direction::Up => (self.pos.0 as i32 - 1, self.pos.1 as i32),
+            Direction::Down => (self.pos.0 as i32 + 1, self.pos.1 as i32),
+            Direction::Right => (self.pos.0 as i32, self.pos.1 as i32 + 1),
+            Direction::Left => (self.pos.0 as i32, self.pos.1 as i32 - 1),
         };
         // Check if we go out of bounce
-        if next_pos.0 < 0 || next_pos.0 >= map.len() as i32 ||
-           next_pos.1 < 0 || next_pos.1 >= map[0].len() as i32
+        if next_pos.0 < 0
+            || next_pos.0 >= map.len() as i32
+            || next_pos.1 < 0
+            || next_pos.1 >= map[0].len() as i32
         {
             return None;
         }
 
         match map[next_pos.0 as usize][next_pos.1 as usize] {
-            true  => Some(Guard { pos: (next_pos.0 as usize, next_pos.1 as usize), dir: self.dir }),
-            false => Some(Guard { pos: self.pos, dir: self.dir.rotate() }),
+            true => Some(Guard {
+                pos: (next_pos.0 as usize, next_pos.1 as usize),
+                dir: self.dir,
+            }),
+            false => Some(Guard {
+                pos: self.pos,
+                dir: self.dir.rotate(),
+            }),
         }
     }
 }
 
 fn parse(input: &str) -> (Guard, Vec<Vec<bool>>) {
     let mut guard: Option<Guard> = None;
-    let map: Vec<Vec<bool>> = input.lines()
+    let map: Vec<Vec<bool>> = input
+        .lines()
         .enumerate()
-        .map(|(i, line)| line.chars()
-            .enumerate()
-            .map(|(j, c)| match c {
-                '#' => false,
-                '.' => true,
-                _ => { guard = Guard::new((i, j), c); true }
-            })
-            .collect()
-        )
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, c)| match c {
+                    '#' => false,
+                    '.' => true,
+                    _ => {
+                        guard = Guard::new((i, j), c);
+                        true
+                    }
+                })
+                .collect()
+        })
         .collect();
-    
+
     (guard.unwrap(), map)
 }
 
-fn sim(guard: &mut Guard, map: &Vec<Vec<bool>>, mut positions: HashSet<Guard>) -> Option<u32> {
+fn sim(guard: &mut Guard, map: &[Vec<bool>], mut positions: HashSet<Guard>) -> Option<u32> {
     positions.insert(*guard);
     while let Some(next_guard) = guard.next_pos(map) {
         if positions.contains(&next_guard) {
@@ -87,7 +102,13 @@ fn sim(guard: &mut Guard, map: &Vec<Vec<bool>>, mut positions: HashSet<Guard>) -
         positions.insert(next_guard);
         *guard = next_guard;
     }
-    Some(positions.into_iter().map(|g| g.pos).collect::<HashSet<(usize, usize)>>().len() as u32)
+    Some(
+        positions
+            .into_iter()
+            .map(|g| g.pos)
+            .collect::<HashSet<(usize, usize)>>()
+            .len() as u32,
+    )
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -95,20 +116,22 @@ pub fn part_one(input: &str) -> Option<u32> {
     sim(&mut guard, &map, HashSet::new())
 }
 
-fn extra_obstacle_sim(guard: &mut Guard, map: &mut Vec<Vec<bool>>) -> Option<u32> {
-    let original_guard = guard.clone();
+fn extra_obstacle_sim(guard: &mut Guard, map: &mut [Vec<bool>]) -> Option<u32> {
+    let original_guard = *guard;
     let mut obstacles: HashSet<(usize, usize)> = HashSet::new();
 
     while let Some(next_guard) = guard.next_pos(map) {
         // Add Obstacle
         let front_pos = guard.next_pos(map).unwrap().pos;
-        if front_pos != guard.pos && !obstacles.contains(&front_pos) && map[front_pos.0][front_pos.1] {
+        if front_pos != guard.pos
+            && !obstacles.contains(&front_pos)
+            && map[front_pos.0][front_pos.1]
+        {
             map[front_pos.0][front_pos.1] = false;
 
             // Find if it has a loop
-            match sim(&mut original_guard.clone(), &map, HashSet::new()) {
-                None => { obstacles.insert(front_pos); },
-                Some(_) => {},
+            if sim(&mut original_guard.clone(), map, HashSet::new()).is_none() {
+                obstacles.insert(front_pos);
             };
             map[front_pos.0][front_pos.1] = true;
         }
@@ -1,5 +1,4 @@
 use itertools::Itertools;
-use std::collections::HashSet;
 
 advent_of_code::solution!(7);
 
@@ -12,28 +11,32 @@ struct Operator {
 impl Operator {
     fn from_string(result: &str, values: Vec<&str>) -> Self {
         let result = result.parse::<u64>().unwrap();
-        let values = values.into_iter()
+        let values = values
+            .into_iter()
             .map(|value| value.parse::<u64>().unwrap())
             .collect::<Vec<u64>>();
-        Operator{ result, values }
+        Operator { result, values }
     }
 
-    fn eval(&self, operations: &Vec<char>) -> Option<u64> {
+    fn eval(&self, operations: &[char]) -> Option<u64> {
         let start: u64 = *self.values.first().unwrap();
-        let res = self.values.iter()
-            .skip(1)
-            .zip(operations.iter())
-            .fold(start, |acc, (val, op)| match op {
+        let res = self.values.iter().skip(1).zip(operations.iter()).fold(
+            start,
+            |acc, (val, op)| match op {
                 '+' => acc + *val,
                 '*' => acc * *val,
                 '|' => concatenate_digits(acc, *val),
                 _ => panic!("Invalid operation!"),
-            });
+            },
+        );
         Some(res)
     }
 
     fn valid(&self, valid_operations: Vec<char>) -> bool {
-        for op in (0..self.values.len()).map(|i| valid_operations.iter().cloned()).multi_cartesian_product() {
+        for op in (0..self.values.len())
+            .map(|_| valid_operations.iter().cloned())
+            .multi_cartesian_product()
+        {
             if let Some(res) = self.eval(&op) {
                 if res == self.result {
                     return true;
@@ -54,7 +57,8 @@ fn concatenate_digits(mut a: u64, b: u64) -> u64 {
 }
 
 fn parse(input: &str) -> Vec<Operator> {
-    input.lines()
+    input
+        .lines()
         .map(|line| line.split_once(": ").unwrap())
         .map(|(r, v)| (r, v.split_whitespace().collect::<Vec<&str>>()))
         .map(|(result, values)| Operator::from_string(result, values))
@@ -62,17 +66,23 @@ fn parse(input: &str) -> Vec<Operator> {
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
-    Some(parse(input).into_iter()
-        .filter(|op| op.valid(vec!['+', '*']))
-        .map(|op| op.result)
-        .sum())
+    Some(
+        parse(input)
+            .into_iter()
+            .filter(|op| op.valid(vec!['+', '*']))
+            .map(|op| op.result)
+            .sum(),
+    )
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    Some(parse(input).into_iter()
-        .filter(|op| op.valid(vec!['+', '*', '|']))
-        .map(|op| op.result)
-        .sum())
+    Some(
+        parse(input)
+            .into_iter()
+            .filter(|op| op.valid(vec!['+', '*', '|']))
+            .map(|op| op.result)
+            .sum(),
+    )
 }
 
 #[cfg(test)]
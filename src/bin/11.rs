@@ -3,9 +3,10 @@ use std::collections::HashMap;
 advent_of_code::solution!(11);
 
 fn parse(input: &str) -> HashMap<u64, u64> {
-    input.split_whitespace()
-         .map(|line| (line.parse().unwrap(), 1))
-         .collect()
+    input
+        .split_whitespace()
+        .map(|line| (line.parse().unwrap(), 1))
+        .collect()
 }
 
 fn stone_len(mut stone: u64) -> usize {
@@ -29,10 +30,14 @@ fn split_stone(mut stone: u64, len: usize) -> Vec<u64> {
 }
 
 fn apply_rule(stone: u64, memo: &mut HashMap<u64, Vec<u64>>) -> Vec<u64> {
-    if let Some(result) = memo.get(&stone) { return result.clone(); }
-    if stone == 0 { return vec![1]; }
+    if let Some(result) = memo.get(&stone) {
+        return result.clone();
+    }
+    if stone == 0 {
+        return vec![1];
+    }
     let len = stone_len(stone);
-    if len % 2 == 0 {
+    if len.is_multiple_of(2) {
         split_stone(stone, len)
     } else {
         vec![stone * 2024]
@@ -41,9 +46,14 @@ fn apply_rule(stone: u64, memo: &mut HashMap<u64, Vec<u64>>) -> Vec<u64> {
 
 fn step(stones: HashMap<u64, u64>, memo: &mut HashMap<u64, Vec<u64>>) -> HashMap<u64, u64> {
     let mut new_stones = HashMap::new();
-    stones.into_iter()
-        .map(|(stone, instances)| apply_rule(stone, memo).into_iter().map(|new_stone| (new_stone, instances)).collect::<Vec<(u64, u64)>>())
-        .flatten()
+    stones
+        .into_iter()
+        .flat_map(|(stone, instances)| {
+            apply_rule(stone, memo)
+                .into_iter()
+                .map(|new_stone| (new_stone, instances))
+                .collect::<Vec<(u64, u64)>>()
+        })
         .for_each(|(stone, instances)| *new_stones.entry(stone).or_insert(0) += instances);
     new_stones
 }
@@ -51,14 +61,18 @@ fn step(stones: HashMap<u64, u64>, memo: &mut HashMap<u64, Vec<u64>>) -> HashMap
 pub fn part_one(input: &str) -> Option<u64> {
     let mut stones = parse(input);
     let mut memo = HashMap::new();
-    for _ in 0..25 { stones = step(stones, &mut memo); }
+    for _ in 0..25 {
+        stones = step(stones, &mut memo);
+    }
     stones.values().sum::<u64>().into()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let mut stones = parse(input);
     let mut memo = HashMap::new();
-    for _ in 0..75 { stones = step(stones, &mut memo); }
+    for _ in 0..75 {
+        stones = step(stones, &mut memo);
+    }
     stones.values().sum::<u64>().into()
 }
 
@@ -3,9 +3,7 @@ advent_of_code::solution!(12);
 use std::collections::HashSet;
 
 fn parse(input: &str) -> Vec<Vec<char>> {
-    input.lines()
-         .map(|line| line.chars().collect())
-         .collect()
+    input.lines().map(|line| line.chars().collect()).collect()
 }
 
 #[derive(Debug, Clone)]
@@ -19,13 +17,14 @@ impl std::ops::Add for Field {
 
     fn add(self, other: Self) -> Self {
         Self {
-            area: self.area.into_iter().chain(other.area.into_iter()).collect(),
-            perimeter: self.perimeter.into_iter().chain(other.perimeter.into_iter()).collect(),
+            area: self.area.into_iter().chain(other.area).collect(),
+            perimeter: self.perimeter.into_iter().chain(other.perimeter).collect(),
         }
     }
 }
 
 impl Field {
+    #[allow(clippy::type_complexity)]
     fn new((area, perimeter): (Vec<(i32, i32)>, Vec<(i32, i32)>)) -> Self {
         Self { area, perimeter }
     }
@@ -43,30 +42,38 @@ impl Field {
         let mut used = HashSet::new();
         // Exterior angles
         for &(i, j) in self.perimeter.iter() {
-            if !used.contains(&((i, j), (i + 1, j + 1))) && self.perimeter.contains(&(i + 1, j + 1)) 
+            if !used.contains(&((i, j), (i + 1, j + 1)))
+                && self.perimeter.contains(&(i + 1, j + 1))
                 && (self.area.contains(&(i + 1, j)) || self.area.contains(&(i, j + 1)))
-                && !(self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j + 1))) {
+                && !(self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j + 1)))
+            {
                 // println!("{} {} | {} {}", i, j, i + 1, j + 1);
                 angles += 1;
                 used.insert(((i, j), (i + 1, j + 1)));
             }
-            if !used.contains(&((i - 1, j + 1), (i, j))) && self.perimeter.contains(&(i - 1, j + 1))
+            if !used.contains(&((i - 1, j + 1), (i, j)))
+                && self.perimeter.contains(&(i - 1, j + 1))
                 && (self.area.contains(&(i - 1, j)) || self.area.contains(&(i, j + 1)))
-                && !(self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j + 1))) {
+                && !(self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j + 1)))
+            {
                 // println!("{} {} | {} {}", i, j, i - 1, j + 1);
                 angles += 1;
                 used.insert(((i - 1, j + 1), (i, j)));
             }
-            if !used.contains(&((i - 1, j - 1), (i, j))) && self.perimeter.contains(&(i - 1, j - 1))
+            if !used.contains(&((i - 1, j - 1), (i, j)))
+                && self.perimeter.contains(&(i - 1, j - 1))
                 && (self.area.contains(&(i - 1, j)) || self.area.contains(&(i, j - 1)))
-                && !(self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j - 1))) {
+                && !(self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j - 1)))
+            {
                 // println!("{} {} | {} {}", i, j, i - 1, j - 1);
                 angles += 1;
                 used.insert(((i - 1, j - 1), (i, j)));
             }
-            if !used.contains(&((i, j), (i + 1, j - 1))) && self.perimeter.contains(&(i + 1, j - 1))
+            if !used.contains(&((i, j), (i + 1, j - 1)))
+                && self.perimeter.contains(&(i + 1, j - 1))
                 && (self.area.contains(&(i + 1, j)) || self.area.contains(&(i, j - 1)))
-                && !(self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j - 1))) {
+                && !(self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j - 1)))
+            {
                 // println!("{} {} | {} {}", i, j, i + 1, j - 1);
                 angles += 1;
                 used.insert(((i, j), (i + 1, j - 1)));
@@ -75,26 +82,34 @@ impl Field {
         // Interior angles
         let mut used = HashSet::new();
         for &(i, j) in self.perimeter.iter() {
-            if self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j + 1)) 
-                && !used.contains(&((i, j), (i, j + 1), (i + 1, j))) {
+            if self.area.contains(&(i + 1, j))
+                && self.area.contains(&(i, j + 1))
+                && !used.contains(&((i, j), (i, j + 1), (i + 1, j)))
+            {
                 // println!("{} {} | {} {} | {} {}", i, j, i + 1, j, i, j + 1);
                 angles += 1;
                 used.insert(((i, j), (i, j + 1), (i + 1, j)));
             }
-            if self.area.contains(&(i + 1, j)) && self.area.contains(&(i, j - 1))
-                && !used.contains(&((i, j - 1), (i, j), (i + 1, j))) {
+            if self.area.contains(&(i + 1, j))
+                && self.area.contains(&(i, j - 1))
+                && !used.contains(&((i, j - 1), (i, j), (i + 1, j)))
+            {
                 // println!("{} {} | {} {} | {} {}", i, j, i + 1, j, i, j - 1);
                 angles += 1;
                 used.insert(((i, j - 1), (i, j), (i + 1, j)));
             }
-            if self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j + 1))
-                && !used.contains(&((i - 1, j), (i, j), (i, j + 1))) {
+            if self.area.contains(&(i - 1, j))
+                && self.area.contains(&(i, j + 1))
+                && !used.contains(&((i - 1, j), (i, j), (i, j + 1)))
+            {
                 // println!("{} {} | {} {} | {} {}", i, j, i - 1, j, i, j + 1);
                 angles += 1;
                 used.insert(((i - 1, j), (i, j), (i, j + 1)));
             }
-            if self.area.contains(&(i - 1, j)) && self.area.contains(&(i, j - 1))
-                && !used.contains(&((i - 1, j), (i, j - 1), (i, j))) {
+            if self.area.contains(&(i - 1, j))
+                && self.area.contains(&(i, j - 1))
+                && !used.contains(&((i - 1, j), (i, j - 1), (i, j)))
+            {
                 // println!("{} {} | {} {} | {} {}", i, j, i - 1, j, i, j - 1);
                 angles += 1;
                 used.insert(((i - 1, j), (i, j - 1), (i, j)));
@@ -104,11 +119,23 @@ impl Field {
     }
 }
 
-fn score(grid: &Vec<Vec<char>>, used: &mut Vec<Vec<bool>>, i: usize, j: usize, field: char) -> Field {
-    if i < grid.len() && j < grid[i].len() && used[i][j] && grid[i][j] == field { return Field::new((Vec::new(), Vec::new())); }
-    if i >= grid.len() || j >= grid[i].len() { return Field::new((Vec::new(), vec![(i as i32, j as i32)])); }
+fn score(
+    grid: &Vec<Vec<char>>,
+    used: &mut Vec<Vec<bool>>,
+    i: usize,
+    j: usize,
+    field: char,
+) -> Field {
+    if i < grid.len() && j < grid[i].len() && used[i][j] && grid[i][j] == field {
+        return Field::new((Vec::new(), Vec::new()));
+    }
+    if i >= grid.len() || j >= grid[i].len() {
+        return Field::new((Vec::new(), vec![(i as i32, j as i32)]));
+    }
 
-    if grid[i][j] != field { return Field::new((Vec::new(), vec![(i as i32, j as i32)])); }
+    if grid[i][j] != field {
+        return Field::new((Vec::new(), vec![(i as i32, j as i32)]));
+    }
 
     used[i][j] = true;
     let mut ans = Field::new((vec![(i as i32, j as i32)], Vec::new()));
@@ -155,15 +182,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1930));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1206));
+    advent_of_code::example_tests! {
+        test_part_one: part_one => { "" => Some(1930), "a" => Some(140), "b" => Some(772) },
+        test_part_two: part_two => { "" => Some(1206), "a" => Some(80), "b" => Some(436) },
     }
 }
@@ -3,13 +3,13 @@ use regex::Regex;
 advent_of_code::solution!(13);
 
 fn parse(input: &str) -> Vec<Puzzle> {
-    input.split("\n\n")
-         .map(|p| Puzzle::parse(p))
-         .collect()
+    input.split("\n\n").map(Puzzle::parse).collect()
 }
 
 fn gcd<T>(mut a: T, mut b: T) -> T
-where T: Copy + PartialOrd + std::ops::Sub<Output = T> {
+where
+    T: Copy + PartialOrd + std::ops::Sub<Output = T>,
+{
     while a != b {
         if a > b {
             a = a - b;
@@ -33,7 +33,8 @@ impl Puzzle {
             re.captures_iter(line)
                 .map(|c| c.extract())
                 .map(|(_, [a, b])| (a.parse::<i128>().unwrap(), b.parse::<i128>().unwrap()))
-                .next().unwrap()
+                .next()
+                .unwrap()
         }
         let re: Regex = Regex::new(r"[\+=](\d*),.*[\+=](\d*)").unwrap();
         let lines: Vec<&str> = input.lines().collect();
@@ -45,13 +46,13 @@ impl Puzzle {
     }
 
     /*
-        (a b) (x) = (g_1)
-        (c d) (y) = (g_2)
-        
-        (a b)           (x) = (g_1)
-        (0 d - c b / a) (y) = (g_2 - c g_1 / a)
-            -> y = (g_2 - c g_1 / a) / (d - c b / a)
-     */
+       (a b) (x) = (g_1)
+       (c d) (y) = (g_2)
+
+       (a b)           (x) = (g_1)
+       (0 d - c b / a) (y) = (g_2 - c g_1 / a)
+           -> y = (g_2 - c g_1 / a) / (d - c b / a)
+    */
     fn solve2(&self) -> Option<u128> {
         let (a, c) = self.a;
         let (b, d) = self.b;
@@ -60,7 +61,7 @@ impl Puzzle {
         // Normalize
         let gcd = gcd(a, c);
         let b = b * c / gcd;
-        let d = d *  a / gcd;
+        let d = d * a / gcd;
         let g_1 = g_1 * c / gcd;
         let g_2 = g_2 * a / gcd;
         let a = a * c / gcd;
@@ -68,11 +69,17 @@ impl Puzzle {
         // Solve
         let d = d - b;
         let g_2 = g_2 - g_1;
-        if d == 0 || g_2.rem_euclid(d) != 0 { return None; }
+        if d == 0 || g_2.rem_euclid(d) != 0 {
+            return None;
+        }
         let y = g_2 / d;
-        if y < 0 || (g_1 - b * y).rem_euclid(a) != 0 { return None; }
+        if y < 0 || (g_1 - b * y).rem_euclid(a) != 0 {
+            return None;
+        }
         let x = (g_1 - b * y) / a;
-        if x < 0 { return None; }
+        if x < 0 {
+            return None;
+        }
 
         Some((x * 3 + y) as u128)
     }
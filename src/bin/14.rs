@@ -21,7 +21,8 @@ impl Robot {
                 p: (p1.parse::<i32>().unwrap(), p2.parse::<i32>().unwrap()),
                 v: (v1.parse::<i32>().unwrap(), v2.parse::<i32>().unwrap()),
             })
-            .next().unwrap()
+            .next()
+            .unwrap()
     }
 
     fn step(&mut self) {
@@ -46,23 +47,19 @@ impl Robot {
 }
 
 fn parse(input: &str) -> Vec<Robot> {
-    input.lines()
-        .map(|line| Robot::parse(line))
-        .collect()
+    input.lines().map(Robot::parse).collect()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut robots = parse(input);
 
+    robots.iter_mut().for_each(|robot| {
+        for _ in 1..=100 {
+            robot.step();
+        }
+    });
 
-    robots.iter_mut()
-        .for_each(|robot| {
-            for _ in 1..=100 {
-                robot.step();
-            }
-        });
-
-    let mut quadrants = vec![0; 4];
+    let mut quadrants = [0; 4];
     for robot in robots {
         if let Some(quadrant) = robot.get_quadrant() {
             quadrants[quadrant] += 1;
@@ -77,12 +74,11 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut best = (0, u64::MAX);
 
     for k in 1..10000 {
-        robots.iter_mut()
-            .for_each(|robot| {
-                robot.step();
-            });
+        robots.iter_mut().for_each(|robot| {
+            robot.step();
+        });
 
-        let mut quadrants = vec![0; 4];
+        let mut quadrants = [0; 4];
         for robot in &robots {
             if let Some(quadrant) = robot.get_quadrant() {
                 quadrants[quadrant] += 1;
@@ -16,13 +16,13 @@ impl TryFrom<char> for Directions {
             '>' => Ok(Self::Right),
             '^' => Ok(Self::Up),
             'v' => Ok(Self::Down),
-            _   => Err("Invalid direction"),
+            _ => Err("Invalid direction"),
         }
     }
 }
 
 impl From<Directions> for (isize, isize) {
-    fn from(value: Directions) -> Self{
+    fn from(value: Directions) -> Self {
         match value {
             Directions::Left => (0, -1),
             Directions::Right => (0, 1),
@@ -49,37 +49,56 @@ impl TryFrom<char> for Cell {
             '@' => Ok(Self::Empty),
             '#' => Ok(Self::Wall),
             'O' => Ok(Self::Box),
-            _   => Err(format!("Invalid cell {value}")),
+            _ => Err(format!("Invalid cell {value}")),
         }
     }
 }
 
 fn parse(input: &str) -> (Vec<Vec<Cell>>, (usize, usize), Vec<Directions>) {
     let mut start = (0, 0);
-    let (map, sequence) = input.split_once("\n\n").expect("Input should contain a map and a sequence");
-    let map = map.lines()
+    let (map, sequence) = input
+        .split_once("\n\n")
+        .expect("Input should contain a map and a sequence");
+    let map = map
+        .lines()
         .enumerate()
-        .map(|(i, line)| line.chars().enumerate().map(|(j, c)| {
-            if c == '@' { start = (i, j); }
-            Cell::try_from(c).unwrap()
-        }).collect())
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    if c == '@' {
+                        start = (i, j);
+                    }
+                    Cell::try_from(c).unwrap()
+                })
+                .collect()
+        })
         .collect();
-    let moves = sequence.lines()
+    let moves = sequence
+        .lines()
         .flat_map(|line| line.chars().map(|c| Directions::try_from(c).unwrap()))
         .collect();
     (map, start, moves)
 }
 
-fn simulate(map: &mut Vec<Vec<Cell>>, pos: (usize, usize), dir: Directions, current_cell: Cell) -> Result<(usize, usize), (usize, usize)> {
+fn simulate(
+    map: &mut Vec<Vec<Cell>>,
+    pos: (usize, usize),
+    dir: Directions,
+    current_cell: Cell,
+) -> Result<(usize, usize), (usize, usize)> {
     let (dx, dy) = dir.into();
-    let (new_x, new_y) = ((pos.0 as isize + dx) as usize, (pos.1 as isize + dy) as usize);
+    let (new_x, new_y) = (
+        (pos.0 as isize + dx) as usize,
+        (pos.1 as isize + dy) as usize,
+    );
 
     match map[new_x][new_y] {
         Cell::Wall => Err(pos),
         Cell::Empty => {
             map[new_x][new_y] = current_cell;
             Ok((new_x, new_y))
-        },
+        }
         Cell::Box => {
             let mut new_map = map.clone();
             new_map[new_x][new_y] = current_cell;
@@ -87,25 +106,25 @@ fn simulate(map: &mut Vec<Vec<Cell>>, pos: (usize, usize), dir: Directions, curr
                 Ok(_) => {
                     *map = new_map;
                     Ok((new_x, new_y))
-                },
+                }
                 Err(_) => Err(pos),
             }
-        },
+        }
         Cell::LeftBox => {
             let mut new_map = map.clone();
             match current_cell {
                 Cell::Empty => {
                     new_map[new_x][new_y] = Cell::Empty;
                     new_map[new_x][new_y + 1] = Cell::Empty;
-                },
+                }
                 Cell::LeftBox => {
                     new_map[new_x][new_y] = Cell::LeftBox;
                     new_map[new_x][new_y + 1] = Cell::Empty;
-                },
+                }
                 Cell::RightBox => {
                     new_map[new_x][new_y] = Cell::RightBox;
                     new_map[new_x][new_y + 1] = Cell::Empty;
-                },
+                }
                 _ => unreachable!(),
             }
             match (
@@ -115,25 +134,25 @@ fn simulate(map: &mut Vec<Vec<Cell>>, pos: (usize, usize), dir: Directions, curr
                 (Ok(_), Ok(_)) => {
                     *map = new_map;
                     Ok((new_x, new_y))
-                },
+                }
                 _ => Err(pos),
             }
-        },
+        }
         Cell::RightBox => {
             let mut new_map = map.clone();
             match current_cell {
                 Cell::Empty => {
                     new_map[new_x][new_y - 1] = Cell::Empty;
                     new_map[new_x][new_y] = Cell::Empty;
-                },
+                }
                 Cell::LeftBox => {
                     new_map[new_x][new_y - 1] = Cell::Empty;
                     new_map[new_x][new_y] = Cell::LeftBox;
-                },
+                }
                 Cell::RightBox => {
                     new_map[new_x][new_y - 1] = Cell::Empty;
                     new_map[new_x][new_y] = Cell::RightBox;
-                },
+                }
                 _ => unreachable!(),
             }
             match (
@@ -143,7 +162,7 @@ fn simulate(map: &mut Vec<Vec<Cell>>, pos: (usize, usize), dir: Directions, curr
                 (Ok(_), Ok(_)) => {
                     *map = new_map;
                     Ok((new_x, new_y))
-                },
+                }
                 _ => Err(pos),
             }
         }
@@ -151,16 +170,21 @@ fn simulate(map: &mut Vec<Vec<Cell>>, pos: (usize, usize), dir: Directions, curr
 }
 
 #[allow(dead_code)]
-fn print_map(map: &Vec<Vec<Cell>>, pos: (usize, usize)) {
-    let mut char_map = map.iter()
-        .map(|row| row.iter().map(|cell| match cell {
-            Cell::Wall => '#',
-            Cell::Box => 'O',
-            Cell::Empty => '.',
-            Cell::LeftBox => '[',
-            Cell::RightBox => ']',
-        }).collect()
-        ).collect::<Vec<Vec<char>>>();
+fn print_map(map: &[Vec<Cell>], pos: (usize, usize)) {
+    let mut char_map = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    Cell::Wall => '#',
+                    Cell::Box => 'O',
+                    Cell::Empty => '.',
+                    Cell::LeftBox => '[',
+                    Cell::RightBox => ']',
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<char>>>();
     char_map[pos.0][pos.1] = '@';
     for row in char_map {
         println!("{}", row.iter().collect::<String>());
@@ -168,6 +192,7 @@ fn print_map(map: &Vec<Vec<Cell>>, pos: (usize, usize)) {
     println!("\n\n");
 }
 
+#[allow(clippy::needless_range_loop)]
 pub fn part_one(input: &str) -> Option<u64> {
     let (mut map, mut pos, moves) = parse(input);
 
@@ -192,15 +217,21 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(ans)
 }
 
+#[allow(clippy::needless_range_loop)]
 pub fn part_two(input: &str) -> Option<u64> {
     let (mut map, mut pos, moves) = parse(input);
 
     // Double map
-    map = map.into_iter()
-        .map(|row| row.into_iter().flat_map(|cell| match cell {
-            Cell::Box => vec![Cell::LeftBox, Cell::RightBox],
-            _ => vec![cell, cell]
-        }).collect())
+    map = map
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .flat_map(|cell| match cell {
+                    Cell::Box => vec![Cell::LeftBox, Cell::RightBox],
+                    _ => vec![cell, cell],
+                })
+                .collect()
+        })
         .collect();
     pos = (pos.0, pos.1 * 2);
 
@@ -1,8 +1,10 @@
-use std::collections::{BinaryHeap, HashSet};
 use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
 use std::vec;
 
-advent_of_code::solution!(16);
+use advent_of_code::template::Solution;
+
+advent_of_code::solution!(16, Maze);
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 struct State {
@@ -54,10 +56,10 @@ impl State {
 
     fn rotate_clock(&self) -> Self {
         let direction = match self.direction {
-            ( 0,  1) => ( 1,  0),
-            ( 1,  0) => ( 0, -1),
-            ( 0, -1) => (-1,  0),
-            (-1,  0) => ( 0,  1),
+            (0, 1) => (1, 0),
+            (1, 0) => (0, -1),
+            (0, -1) => (-1, 0),
+            (-1, 0) => (0, 1),
             _ => panic!("Invalid direction"),
         };
         Self {
@@ -70,10 +72,10 @@ impl State {
 
     fn rotate_counterclock(&self) -> Self {
         let direction = match self.direction {
-            ( 0,  1) => (-1,  0),
-            (-1,  0) => ( 0, -1),
-            ( 0, -1) => ( 1,  0),
-            ( 1,  0) => ( 0,  1),
+            (0, 1) => (-1, 0),
+            (-1, 0) => (0, -1),
+            (0, -1) => (1, 0),
+            (1, 0) => (0, 1),
             _ => panic!("Invalid direction"),
         };
         Self {
@@ -97,7 +99,7 @@ impl PartialOrd for State {
     }
 }
 
-fn dijktra(map: &Vec<Vec<bool>>, start: State, goal: (usize, usize)) -> Vec<State> {
+fn dijktra(map: &[Vec<bool>], start: State, goal: (usize, usize)) -> Vec<State> {
     let mut heap = BinaryHeap::new();
     let mut dist = vec![vec![vec![u64::MAX; map[0].len()]; map.len()]; 4];
 
@@ -122,10 +124,14 @@ fn dijktra(map: &Vec<Vec<bool>>, start: State, goal: (usize, usize)) -> Vec<Stat
 
         // Move forward
         for next_state in state.possible_moves() {
-            if !map[next_state.position.0][next_state.position.1] { continue; }
-            
+            if !map[next_state.position.0][next_state.position.1] {
+                continue;
+            }
+
             let pos = next_state.map_pos();
-            if next_state.score <= dist[pos.0][pos.1][pos.2] && next_state.score <= best.unwrap_or(u64::MAX) {
+            if next_state.score <= dist[pos.0][pos.1][pos.2]
+                && next_state.score <= best.unwrap_or(u64::MAX)
+            {
                 dist[pos.0][pos.1][pos.2] = next_state.score;
                 heap.push(next_state);
             }
@@ -134,42 +140,49 @@ fn dijktra(map: &Vec<Vec<bool>>, start: State, goal: (usize, usize)) -> Vec<Stat
     paths
 }
 
-fn parse(input: &str) -> (State, (usize, usize), Vec<Vec<bool>>) {
-    let mut start = None;
-    let mut goal = (0, 0);
-    let map = input.lines()
-        .enumerate()
-        .map(|(i, line)| {
-            line.chars()
-                .enumerate()
-                .map(|(j, c)| {
-                    if c == 'S' {
-                        start = Some(State::new((i, j)));
-                    } else if c == 'E' {
-                        goal = (i, j);
-                    }
-                    if c == '#' {
-                        false
-                    } else {
-                        true
-                    }
-                }).collect()
-        }).collect();
-    (start.unwrap(), goal, map)
-}
+struct Maze;
+
+impl Solution for Maze {
+    type Parsed = (State, (usize, usize), Vec<Vec<bool>>);
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Self::Parsed {
+        let mut start = None;
+        let mut goal = (0, 0);
+        let map = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        if c == 'S' {
+                            start = Some(State::new((i, j)));
+                        } else if c == 'E' {
+                            goal = (i, j);
+                        }
+                        c != '#'
+                    })
+                    .collect()
+            })
+            .collect();
+        (start.unwrap(), goal, map)
+    }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (state, goal, map) = parse(input);
-    dijktra(&map, state, goal).first().map(|s| s.score)
-}
+    fn part_one((state, goal, map): &Self::Parsed) -> Option<u64> {
+        dijktra(map, state.clone(), *goal).first().map(|s| s.score)
+    }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (state, goal, map) = parse(input);
-    Some(dijktra(&map, state, goal).iter()
-        .map(|s| s.path_len.iter())
-        .flatten()
-        .collect::<HashSet<_>>()
-        .len() as u64)
+    fn part_two((state, goal, map): &Self::Parsed) -> Option<u64> {
+        Some(
+            dijktra(map, state.clone(), *goal)
+                .iter()
+                .flat_map(|s| s.path_len.iter())
+                .collect::<HashSet<_>>()
+                .len() as u64,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -178,13 +191,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = Maze::part_one(&Maze::parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(11048));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = Maze::part_two(&Maze::parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(64));
     }
 }
@@ -18,7 +18,7 @@ impl Register {
             5 => self.out(operand),
             6 => self.bdv(operand),
             7 => self.cdv(operand),
-            _ => unreachable!()
+            _ => unreachable!(),
         }
     }
 
@@ -28,12 +28,12 @@ impl Register {
             4 => self.a,
             5 => self.b,
             6 => self.c,
-            _ => unreachable!()
+            _ => unreachable!(),
         }
     }
 
     fn adv(&mut self, operand: u64) -> (Option<u64>, Option<usize>) {
-        self.a = self.a / (1 << self.combo_value(operand));
+        self.a /= 1 << self.combo_value(operand);
         (None, None)
     }
 
@@ -77,17 +77,24 @@ impl Register {
 
 fn parse(input: &str) -> (Register, Vec<u64>) {
     let (registers, program) = input.split_once("\n\n").unwrap();
-    let registers: Vec<_> = registers.lines()
+    let registers: Vec<_> = registers
+        .lines()
         .map(|line| line.split_once(": ").unwrap().1.parse::<u64>().unwrap())
         .collect();
-    let program = program.split_once(": ").unwrap().1.split(',').map(|s| s.trim().parse::<u64>().unwrap()).collect();
+    let program = program
+        .split_once(": ")
+        .unwrap()
+        .1
+        .split(',')
+        .map(|s| s.trim().parse::<u64>().unwrap())
+        .collect();
     (
         Register {
             a: registers[0],
             b: registers[1],
             c: registers[2],
         },
-        program
+        program,
     )
 }
 
@@ -102,7 +109,7 @@ fn solve(reg: &mut Register, program: &[u64]) -> Vec<u64> {
             (Some(out), None) => {
                 output.push(out);
                 pos += 2;
-            },
+            }
             (None, Some(jump)) => pos = jump,
         };
     }
@@ -112,10 +119,12 @@ fn solve(reg: &mut Register, program: &[u64]) -> Vec<u64> {
 
 pub fn part_one(input: &str) -> Option<String> {
     let (mut reg, program) = parse(input);
-    Some(solve(&mut reg, &program).into_iter()
-        .map(|x| x.to_string())
-        .collect::<Vec<_>>()
-        .join(",")
+    Some(
+        solve(&mut reg, &program)
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
     )
 }
 
@@ -128,14 +137,15 @@ fn bfs_inv(program: Vec<u64>) -> Option<u64> {
             let mut reg = Register { a, b: 0, c: 0 };
             let res = solve(&mut reg, &program);
             if res == program[n - k..n] {
-                new_candidates.extend_from_slice((0..8).map(|x| x + a * 8).collect::<Vec<_>>().as_slice());
+                new_candidates
+                    .extend_from_slice((0..8).map(|x| x + a * 8).collect::<Vec<_>>().as_slice());
             }
         }
         candidates = new_candidates;
     }
-    candidates.into_iter()
-        .filter(|&a| solve(&mut Register { a, b: 0, c: 0 }, &program) == program)
-        .next()
+    candidates
+        .into_iter()
+        .find(|&a| solve(&mut Register { a, b: 0, c: 0 }, &program) == program)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
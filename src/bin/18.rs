@@ -5,13 +5,14 @@ advent_of_code::solution!(18);
 static DIRECTIONS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
 
 fn parse(input: &str) -> Vec<(usize, usize)> {
-    input.lines()
-         .map(|line| line.split_once(',').unwrap())
-         .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
-         .collect()
+    input
+        .lines()
+        .map(|line| line.split_once(',').unwrap())
+        .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
+        .collect()
 }
 
-fn dijkstra(grid: &Vec<Vec<i32>>, start: (usize, usize), end: (usize, usize)) -> Option<u64> {
+fn dijkstra(grid: &[Vec<i32>], start: (usize, usize), end: (usize, usize)) -> Option<u64> {
     let mut dist = vec![vec![i32::MIN; grid.len()]; grid.len()];
     let mut heap = BinaryHeap::new();
 
@@ -20,14 +21,22 @@ fn dijkstra(grid: &Vec<Vec<i32>>, start: (usize, usize), end: (usize, usize)) ->
 
     while let Some((cost, (i, j))) = heap.pop() {
         // println!("{} {:?}", cost, (i, j));
-        if grid[i][j] == -1 { continue; }
-        if (i, j) == end { return Some(-cost as u64); }
+        if grid[i][j] == -1 {
+            continue;
+        }
+        if (i, j) == end {
+            return Some(-cost as u64);
+        }
 
-        if cost < dist[i][j] { continue; }
+        if cost < dist[i][j] {
+            continue;
+        }
 
         for (di, dj) in DIRECTIONS {
             let (ni, nj) = (i as i32 + di, j as i32 + dj);
-            if ni < 0 || nj < 0 || ni >= grid.len() as i32 || nj >= grid.len() as i32 { continue; }
+            if ni < 0 || nj < 0 || ni >= grid.len() as i32 || nj >= grid.len() as i32 {
+                continue;
+            }
             let (ni, nj) = (ni as usize, nj as usize);
             if cost - 1 > dist[ni][nj] {
                 dist[ni][nj] = cost - 1;
@@ -48,7 +57,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     dijkstra(&grid, (0, 0), (size, size))
 }
 
-fn simulate(pos: &Vec<(usize, usize)>, time: usize) -> Option<u64> {
+fn simulate(pos: &[(usize, usize)], time: usize) -> Option<u64> {
     let size = *pos.iter().map(|(a, b)| a.max(b)).max().unwrap();
     let mut grid = vec![vec![i32::MAX; size + 1]; size + 1];
     for &(i, j) in pos.iter().take(time) {
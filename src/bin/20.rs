@@ -4,29 +4,36 @@ advent_of_code::solution!(20);
 
 static DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
 
+#[allow(clippy::type_complexity)]
 fn parse(input: &str) -> (Vec<Vec<bool>>, (usize, usize), (usize, usize)) {
     let mut start = (0, 0);
     let mut end = (0, 0);
-    let map = input.lines()
+    let map = input
+        .lines()
         .enumerate()
-        .map(|(i, line)| line.chars().enumerate().map(|(j, c)| match c {
-            '#' => false,
-            '.' => true,
-            'S' => {
-                start = (i, j);
-                true
-            },
-            'E' => {
-                end = (i, j);
-                true
-            }
-            _ => panic!("Invalid character: {}", c),
-        }).collect())
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, c)| match c {
+                    '#' => false,
+                    '.' => true,
+                    'S' => {
+                        start = (i, j);
+                        true
+                    }
+                    'E' => {
+                        end = (i, j);
+                        true
+                    }
+                    _ => panic!("Invalid character: {}", c),
+                })
+                .collect()
+        })
         .collect();
     (map, start, end)
 }
 
-fn bfs(map: &Vec<Vec<bool>>, start: (usize, usize)) -> Vec<Vec<u64>> {
+fn bfs(map: &[Vec<bool>], start: (usize, usize)) -> Vec<Vec<u64>> {
     let mut queue = vec![start];
     let mut dp = vec![vec![u64::MAX; map[0].len()]; map.len()];
     dp[start.0][start.1] = 0;
@@ -38,7 +45,11 @@ fn bfs(map: &Vec<Vec<bool>>, start: (usize, usize)) -> Vec<Vec<u64>> {
             for (dx, dy) in DIRECTIONS.iter() {
                 let new_x = (x as isize + dx) as usize;
                 let new_y = (y as isize + dy) as usize;
-                if new_x < map.len() && new_y < map[0].len() && map[new_x][new_y] && dp[new_x][new_y] > distance {
+                if new_x < map.len()
+                    && new_y < map[0].len()
+                    && map[new_x][new_y]
+                    && dp[new_x][new_y] > distance
+                {
                     dp[new_x][new_y] = distance;
                     next_queue.push((new_x, new_y));
                 }
@@ -60,7 +71,10 @@ fn cheat_directions(size: isize, x: isize, y: isize) -> Vec<((isize, isize), u64
         let mut new_curr = curr.clone();
         for (x, y) in curr.keys() {
             for (dx, dy) in DIRECTIONS.iter() {
-                new_curr.entry((x + dx, y + dy)).and_modify(|v| *v = (*v).min(distance)).or_insert(distance);
+                new_curr
+                    .entry((x + dx, y + dy))
+                    .and_modify(|v| *v = (*v).min(distance))
+                    .or_insert(distance);
             }
         }
         curr = new_curr.clone();
@@ -69,7 +83,12 @@ fn cheat_directions(size: isize, x: isize, y: isize) -> Vec<((isize, isize), u64
     curr.into_iter().collect()
 }
 
-fn cheats(forward_dist: &Vec<Vec<u64>>, backward_dist: &Vec<Vec<u64>>, best: u64, size: isize) -> Option<u64> {
+fn cheats(
+    forward_dist: &[Vec<u64>],
+    backward_dist: &[Vec<u64>],
+    best: u64,
+    size: isize,
+) -> Option<u64> {
     let mut count = 0;
     let directions = cheat_directions(size, 0, 0);
     for x in 0..backward_dist.len() {
@@ -85,7 +104,12 @@ fn cheats(forward_dist: &Vec<Vec<u64>>, backward_dist: &Vec<Vec<u64>>, best: u64
                 if new_x >= backward_dist.len() || new_y >= backward_dist[0].len() {
                     continue;
                 }
-                if backward_dist[x][y] as u128 + forward_dist[new_x][new_y] as u128 + cheat_size as u128 <= best as u128 - 2 { // 100 {
+                if backward_dist[x][y] as u128
+                    + forward_dist[new_x][new_y] as u128
+                    + cheat_size as u128
+                    <= best as u128 - 2
+                {
+                    // 100 {
                     count += 1;
                 }
             }
@@ -94,7 +118,6 @@ fn cheats(forward_dist: &Vec<Vec<u64>>, backward_dist: &Vec<Vec<u64>>, best: u64
     Some(count)
 }
 
-
 pub fn part_one(input: &str) -> Option<u64> {
     let (map, start, end) = parse(input);
     let forward_dist = bfs(&map, end);
@@ -20,39 +20,44 @@ impl Pice {
             "....." => Orientation::Down,
             _ => panic!("Unexpected input"),
         };
-        let values = lines.into_iter()
-            .map(|line| line.chars()
-                .map(|c| match c {
-                '#' => 1,
-                '.' => 0,
-                _ => panic!("Unexpected input"),
+        let values = lines
+            .into_iter()
+            .map(|line| {
+                line.chars().map(|c| match c {
+                    '#' => 1,
+                    '.' => 0,
+                    _ => panic!("Unexpected input"),
                 })
-            )
+            })
             .fold(vec![0; 5], |acc, line| {
                 acc.iter().zip(line).map(|(a, b)| a + b).collect()
             });
-        Self { orientation, values }
+        Self {
+            orientation,
+            values,
+        }
     }
 
     fn can_match(&self, other: &Self) -> bool {
-        if self.orientation == other.orientation { return false; }
-        self.values.iter()
+        if self.orientation == other.orientation {
+            return false;
+        }
+        self.values
+            .iter()
             .zip(other.values.iter())
             .all(|(a, b)| a + b <= 7)
     }
 }
 
 fn parse(input: &str) -> Vec<Pice> {
-    input.split("\n\n")
-         .map(|pice| Pice::new(pice))
-         .collect()
+    input.split("\n\n").map(Pice::new).collect()
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
     let pices = parse(input);
     let mut result = 0;
     for i in 0..pices.len() {
-        for j in i+1..pices.len() {
+        for j in i + 1..pices.len() {
             if pices[i].can_match(&pices[j]) {
                 result += 1;
             }
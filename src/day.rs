@@ -1,3 +1,4 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -80,6 +81,22 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
+/// A [`Day`] is serialized as its number.
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        Self::new(day).ok_or_else(|| serde::de::Error::custom(DayFromStrError))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub fn all_days() -> AllDays {
     AllDays::new()
@@ -114,6 +131,89 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days, parsed from a comma separated list of days and ranges
+/// (e.g. `1-3,17`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Every day of advent.
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The days of the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|day| self.contains(*day))
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut set = Self::default();
+        iter.into_iter().for_each(|day| set.insert(day));
+        set
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::default();
+
+        for item in s.split(',').map(str::trim) {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let start: Day = start.trim().parse().map_err(|_| DaySetFromStrError)?;
+                    let end: Day = end.trim().parse().map_err(|_| DaySetFromStrError)?;
+                    if start > end {
+                        return Err(DaySetFromStrError);
+                    }
+                    (start.0..=end.0).for_each(|day| set.insert(Day(day)));
+                }
+                None => set.insert(item.parse().map_err(|_| DaySetFromStrError)?),
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+/// Formats the set as a comma separated list of days, which parses back into the same set.
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let days: Vec<String> = self.iter().map(|day| day.to_string()).collect();
+        f.write_str(&days.join(","))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days between 1 and 25, such as `5`, `1-10` or `3,5,17`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +234,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +267,35 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn day_set_from_str() {
+        let days = |s: &str| {
+            s.parse::<DaySet>()
+                .map(|set| set.iter().map(Day::into_inner).collect::<Vec<_>>())
+                .ok()
+        };
+
+        assert_eq!(days("7"), Some(vec![7]));
+        assert_eq!(days("1-4"), Some(vec![1, 2, 3, 4]));
+        assert_eq!(days("3,5,17"), Some(vec![3, 5, 17]));
+        assert_eq!(days("1-3,17"), Some(vec![1, 2, 3, 17]));
+        assert_eq!(days("24-25, 2,1"), Some(vec![1, 2, 24, 25]));
+        assert_eq!(days("5-5"), Some(vec![5]));
+        assert_eq!(days("5-3"), None);
+        assert_eq!(days("0-3"), None);
+        assert_eq!(days("26"), None);
+        assert_eq!(days("3,"), None);
+        assert_eq!(days(""), None);
+    }
+
+    #[test]
+    fn day_set_round_trips() {
+        let days: DaySet = "1-3,17".parse().unwrap();
+
+        assert_eq!(days.to_string(), "01,02,03,17");
+        assert_eq!(days.to_string().parse::<DaySet>().unwrap(), days);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -1,10 +1,25 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, check_inputs, completions, decrypt_inputs, download, encrypt_inputs, extract_examples,
+    read, scaffold, solve, status, test, watch_day,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use std::{num::NonZeroUsize, time::Duration};
+
+    use advent_of_code::{
+        template::{
+            baseline,
+            commands::{
+                all::{AllOptions, BaselineOptions},
+                completions::Shell,
+            },
+            config, is_solved, parse_duration, parse_timeout, usage, InputSource, OutputFormat,
+        },
+        Day, DaySet,
+    };
 
     pub enum AppArguments {
         Download {
@@ -13,61 +28,287 @@ mod args {
         Read {
             day: Day,
         },
+        ExtractExamples {
+            day: Day,
+            block: Option<usize>,
+            part_two_block: Option<usize>,
+            list: bool,
+            force: bool,
+        },
         Scaffold {
             day: Day,
+            template: String,
+            answer_type: String,
+            example_cases: Vec<String>,
         },
         Solve {
             day: Day,
             release: bool,
             time: bool,
             submit: Option<u8>,
+            part: Option<u8>,
+            format: OutputFormat,
+            timeout: Option<Duration>,
+            input: InputSource,
+        },
+        Status,
+        Test {
+            days: DaySet,
         },
-        All {
+        WatchDay {
+            day: Day,
+            tests: bool,
+            solve: bool,
             release: bool,
-            time: bool,
+            input: InputSource,
+            interval: Duration,
+        },
+        Completions {
+            shell: Shell,
         },
+        EncryptInputs,
+        DecryptInputs {
+            force: bool,
+        },
+        CheckInputs {
+            install_hook: bool,
+        },
+        All(AllOptions),
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
-            },
-            Some("download") => AppArguments::Download {
+        let Some(subcommand) = args.subcommand()? else {
+            if args.contains(["-h", "--help"]) {
+                println!("{}", usage::overview());
+                process::exit(0);
+            }
+            eprintln!("No command specified.\n\n{}", usage::overview());
+            process::exit(1);
+        };
+
+        let Some(command) = usage::find(&subcommand) else {
+            eprintln!("Unknown command: {subcommand}\n\n{}", usage::overview());
+            process::exit(1);
+        };
+
+        if args.contains(["-h", "--help"]) {
+            println!("{}", usage::command_usage(command));
+            process::exit(0);
+        }
+
+        let defaults = &config::get().defaults;
+
+        let app_args = match command.name {
+            "all" => {
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let format = args
+                    .opt_value_from_str("--format")?
+                    .unwrap_or(defaults.format);
+                let timeout = args
+                    .opt_value_from_fn("--timeout", parse_timeout)?
+                    .unwrap_or(defaults.timeout);
+                let jobs = args
+                    .opt_value_from_str::<_, NonZeroUsize>("--jobs")?
+                    .map_or(defaults.jobs.max(1), NonZeroUsize::get);
+
+                let baseline = BaselineOptions {
+                    save: args.opt_value_from_fn("--save-baseline", baseline::parse_name)?,
+                    compare: args.opt_value_from_fn("--baseline", baseline::parse_name)?,
+                    threshold: args
+                        .opt_value_from_fn("--threshold", parse_threshold)?
+                        .unwrap_or(config::get().bench.threshold),
+                };
+
+                if (baseline.save.is_some() || baseline.compare.is_some()) && !time {
+                    return Err("--save-baseline and --baseline require --time.".into());
+                }
+
+                let solved = match (args.contains("--solved"), args.contains("--unsolved")) {
+                    (true, true) => {
+                        return Err("--solved and --unsolved cannot be used together.".into())
+                    }
+                    (true, false) => Some(true),
+                    (false, true) => Some(false),
+                    (false, false) => None,
+                };
+
+                // free arguments come last, once every flag has been consumed.
+                let days = args
+                    .opt_free_from_str::<DaySet>()?
+                    .unwrap_or_else(DaySet::all);
+                let days = match solved {
+                    Some(solved) => days
+                        .iter()
+                        .filter(|day| is_solved(*day) == solved)
+                        .collect(),
+                    None => days,
+                };
+
+                AppArguments::All(AllOptions {
+                    days,
+                    is_release: release,
+                    is_timed: time,
+                    format,
+                    timeout,
+                    jobs,
+                    baseline,
+                })
+            }
+            "download" => AppArguments::Download {
                 day: args.free_from_str()?,
             },
-            Some("read") => AppArguments::Read {
+            "read" => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            "extract-examples" => AppArguments::ExtractExamples {
+                block: args.opt_value_from_str("--block")?,
+                part_two_block: args.opt_value_from_str("--part-two-block")?,
+                list: args.contains("--list"),
+                force: args.contains("--force"),
                 day: args.free_from_str()?,
             },
-            Some("solve") => AppArguments::Solve {
+            "scaffold" => AppArguments::Scaffold {
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| defaults.template.clone()),
+                answer_type: args
+                    .opt_value_from_str("--answer-type")?
+                    .unwrap_or_else(|| defaults.answer_type.clone()),
+                example_cases: args
+                    .opt_value_from_fn("--examples", parse_cases)?
+                    .unwrap_or_default(),
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
             },
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
+            "solve" => {
+                let input = match (
+                    args.contains("--example"),
+                    args.opt_value_from_str::<_, String>("--input")?,
+                ) {
+                    (true, Some(_)) => {
+                        return Err("--example and --input cannot be used together.".into())
+                    }
+                    (true, None) => InputSource::Example,
+                    (false, Some(path)) if path == "-" => InputSource::Stdin,
+                    (false, Some(path)) => InputSource::File(path),
+                    (false, None) => InputSource::Puzzle,
+                };
+
+                let submit = args.opt_value_from_fn("--submit", parse_part)?;
+
+                if submit.is_some() && input != InputSource::Puzzle {
+                    return Err("--submit can only be used with the puzzle input.".into());
+                }
+
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+
+                if let (Some(submit), Some(part)) = (submit, part) {
+                    if submit != part {
+                        return Err(format!(
+                            "cannot submit part {submit} while only running part {part}."
+                        )
+                        .into());
+                    }
+                }
+
+                AppArguments::Solve {
+                    day: args.free_from_str()?,
+                    release: args.contains("--release"),
+                    submit,
+                    part,
+                    time: args.contains("--time"),
+                    format: args
+                        .opt_value_from_str("--format")?
+                        .unwrap_or(defaults.format),
+                    timeout: args
+                        .opt_value_from_fn("--timeout", parse_timeout)?
+                        .unwrap_or(defaults.timeout),
+                    input,
+                }
             }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
+            "status" => AppArguments::Status,
+            "test" => AppArguments::Test {
+                days: args.opt_free_from_str()?.unwrap_or_else(DaySet::all),
+            },
+            "watch-day" => {
+                // without a selection, both the tests and the solution run.
+                let (tests, solve) = match (args.contains("--tests"), args.contains("--solve")) {
+                    (false, false) => (true, true),
+                    selection => selection,
+                };
+
+                AppArguments::WatchDay {
+                    release: args.contains("--release"),
+                    input: if args.contains("--example") {
+                        InputSource::Example
+                    } else {
+                        InputSource::Puzzle
+                    },
+                    interval: args
+                        .opt_value_from_fn("--interval", parse_duration)?
+                        .unwrap_or(defaults.interval),
+                    day: args.free_from_str()?,
+                    tests,
+                    solve,
+                }
             }
+            "completions" => AppArguments::Completions {
+                shell: args.free_from_str()?,
+            },
+            "encrypt-inputs" => AppArguments::EncryptInputs,
+            "decrypt-inputs" => AppArguments::DecryptInputs {
+                force: args.contains("--force"),
+            },
+            "check-inputs" => AppArguments::CheckInputs {
+                install_hook: args.contains("--install-hook"),
+            },
+            _ => unreachable!("every command in `usage::COMMANDS` is handled"),
         };
 
         let remaining = args.finish();
         if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+            return Err(format!(
+                "unknown argument(s): {remaining:?}, see `cargo {} --help`.",
+                command.alias
+            )
+            .into());
         }
 
         Ok(app_args)
     }
+
+    /// Parses a comma separated list of example cases such as `1,2` or `a,b,c`.
+    fn parse_cases(s: &str) -> Result<Vec<String>, String> {
+        s.split(',')
+            .map(str::trim)
+            .map(|case| {
+                if !case.is_empty() && case.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    Ok(case.to_string())
+                } else {
+                    Err(format!(
+                        "invalid example case \"{case}\", expecting e.g. `1,2` or `a,b`"
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    fn parse_threshold(s: &str) -> Result<f64, String> {
+        match s.trim_end_matches('%').parse::<f64>() {
+            Ok(threshold) if threshold >= 0_f64 => Ok(threshold),
+            _ => Err(format!("expected a positive percentage, got \"{s}\"")),
+        }
+    }
+
+    fn parse_part(s: &str) -> Result<u8, String> {
+        match s {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err(format!("expected part 1 or 2, got \"{s}\"")),
+        }
+    }
 }
 
 fn main() {
@@ -77,16 +318,46 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
+            AppArguments::All(options) => all::handle(&options),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::ExtractExamples {
+                day,
+                block,
+                part_two_block,
+                list,
+                force,
+            } => extract_examples::handle(day, block, part_two_block, list, force),
+            AppArguments::Scaffold {
+                day,
+                template,
+                answer_type,
+                example_cases,
+            } => scaffold::handle(day, &template, &answer_type, &example_cases),
             AppArguments::Solve {
                 day,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                part,
+                format,
+                timeout,
+                input,
+            } => solve::handle(day, release, time, submit, part, format, timeout, input),
+            AppArguments::Status => status::handle(),
+            AppArguments::Test { days } => test::handle(days),
+            AppArguments::WatchDay {
+                day,
+                tests,
+                solve,
+                release,
+                input,
+                interval,
+            } => watch_day::handle(day, tests, solve, release, input, interval),
+            AppArguments::Completions { shell } => completions::handle(shell),
+            AppArguments::EncryptInputs => encrypt_inputs::handle(),
+            AppArguments::DecryptInputs { force } => decrypt_inputs::handle(force),
+            AppArguments::CheckInputs { install_hook } => check_inputs::handle(install_hook),
         },
     };
 }
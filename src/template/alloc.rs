@@ -0,0 +1,206 @@
+/// Opt-in allocation tracking. Build with `--features alloc_stats` to install a counting global allocator,
+/// [`measure`] then reports how much memory a closure allocated.
+///
+/// Counters are kept per thread, so that parts running side by side (see `all --jobs`) do not mix their numbers.
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Allocations made while running a part once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Number of allocations, a reallocation counts as one.
+    pub allocations: u64,
+    /// Total amount of bytes requested, freed memory is not subtracted.
+    pub bytes: u64,
+    /// Highest amount of live bytes at any point.
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs · {} allocated · {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Formats an amount of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Runs `func` and returns the allocations it made on the current thread,
+/// or `None` if the crate was built without the `alloc_stats` feature.
+pub fn measure<R>(func: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    #[cfg(feature = "alloc_stats")]
+    {
+        let (result, stats) = counting::measure(func);
+        (result, Some(stats))
+    }
+
+    #[cfg(not(feature = "alloc_stats"))]
+    {
+        (func(), None)
+    }
+}
+
+#[cfg(feature = "alloc_stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[derive(Clone, Copy)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        // memory allocated on another thread may be freed here, so this can go negative.
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        // `Cell` of a `Copy` type has no destructor, so accessing it never allocates.
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        let _ = COUNTERS.try_with(|counters| {
+            let mut current = counters.get();
+            if allocated > 0 {
+                current.allocations += 1;
+                current.bytes += allocated as u64;
+            }
+            #[allow(clippy::cast_possible_wrap)]
+            {
+                current.live += allocated as i64 - freed as i64;
+            }
+            current.peak = current.peak.max(current.live);
+            counters.set(current);
+        });
+    }
+
+    pub fn measure<R>(func: impl FnOnce() -> R) -> (R, AllocStats) {
+        let start = COUNTERS.with(|counters| {
+            let mut current = counters.get();
+            current.peak = current.live;
+            counters.set(current);
+            current
+        });
+
+        let result = func();
+
+        let end = COUNTERS.with(Cell::get);
+
+        #[allow(clippy::cast_sign_loss)]
+        let stats = AllocStats {
+            allocations: end.allocations - start.allocations,
+            bytes: end.bytes - start.bytes,
+            peak_bytes: (end.peak - start.live).max(0) as u64,
+        };
+
+        (result, stats)
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, AllocStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 2048,
+            peak_bytes: 512,
+        };
+        assert_eq!(stats.to_string(), "12 allocs · 2.0 KiB allocated · 512 B peak");
+    }
+
+    #[cfg(feature = "alloc_stats")]
+    #[test]
+    fn measures_allocations() {
+        let (len, stats) = super::measure(|| {
+            let first = vec![0_u8; 4096];
+            let second = vec![0_u8; 1024];
+            first.len() + second.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(len, 5120);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 5120);
+        assert_eq!(stats.peak_bytes, 5120);
+    }
+}
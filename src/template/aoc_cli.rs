@@ -1,9 +1,15 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    path::PathBuf,
     process::{Command, Output, Stdio},
+    sync::OnceLock,
+    time::Duration,
 };
 
+use regex::Regex;
+
+use crate::template::{config, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW};
 use crate::Day;
 
 #[derive(Debug)]
@@ -19,14 +25,131 @@ impl Display for AocCommandError {
         match self {
             AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
             AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
+            AocCommandError::BadExitStatus(output) => {
+                let text = output_text(output);
+                match text.lines().rev().find(|line| !line.trim().is_empty()) {
+                    Some(line) => {
+                        write!(f, "aoc-cli exited with a non-zero status: {}", line.trim())
+                    }
+                    None => write!(f, "aoc-cli exited with a non-zero status."),
+                }
             }
             AocCommandError::IoError => write!(f, "could not write output files to file system."),
         }
     }
 }
 
+/// A result parsed from the output of aoc-cli, with the full output kept for logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocReply<T> {
+    pub result: T,
+    pub text: String,
+}
+
+/// Whether a wrong answer was too high or too low, if the site said so.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// How the site replied to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitVerdict {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently, the site asks to wait before the next one.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    AlreadySolved,
+    NotUnlocked,
+}
+
+impl SubmitVerdict {
+    /// Reads the verdict from the reply of the site, as printed by aoc-cli or fetched by
+    /// [`crate::template::aoc_client`]. `None` if the reply is not recognized.
+    #[must_use]
+    pub fn parse(reply: &str) -> Option<Self> {
+        let reply = strip_ansi(reply);
+
+        if reply.contains("That's the right answer") {
+            Some(SubmitVerdict::Correct)
+        } else if reply.contains("You gave an answer too recently") {
+            Some(SubmitVerdict::RateLimited {
+                wait: parse_wait(&reply),
+            })
+        } else if reply.contains("That's not the right answer") {
+            let hint = if reply.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if reply.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(SubmitVerdict::Incorrect { hint })
+        } else if reply.contains("Did you already complete it?") {
+            Some(SubmitVerdict::AlreadySolved)
+        } else if is_locked(&reply) {
+            Some(SubmitVerdict::NotUnlocked)
+        } else {
+            None
+        }
+    }
+
+    /// A one line, colored summary of the verdict.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self {
+            SubmitVerdict::Correct => format!("{ANSI_GREEN}{ANSI_BOLD}✔ Correct answer!{ANSI_RESET}"),
+            SubmitVerdict::Incorrect { hint } => {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => ", too high",
+                    Some(Hint::TooLow) => ", too low",
+                    None => "",
+                };
+                format!("{ANSI_RED}{ANSI_BOLD}✖ Wrong answer{hint}.{ANSI_RESET}")
+            }
+            SubmitVerdict::RateLimited { wait } => match wait {
+                Some(wait) => format!(
+                    "{ANSI_YELLOW}{ANSI_BOLD}⏳ Rate limited, wait {}s before submitting again.{ANSI_RESET}",
+                    wait.as_secs()
+                ),
+                None => format!("{ANSI_YELLOW}{ANSI_BOLD}⏳ Rate limited, wait before submitting again.{ANSI_RESET}"),
+            },
+            SubmitVerdict::AlreadySolved => {
+                format!("{ANSI_YELLOW}{ANSI_BOLD}✔ This part is already solved.{ANSI_RESET}")
+            }
+            SubmitVerdict::NotUnlocked => {
+                format!("{ANSI_YELLOW}{ANSI_BOLD}⏳ This puzzle is not unlocked yet.{ANSI_RESET}")
+            }
+        }
+    }
+}
+
+/// The outcome of downloading the input and puzzle of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadResult {
+    Downloaded { input: PathBuf, puzzle: PathBuf },
+    NotUnlocked,
+}
+
+impl DownloadResult {
+    pub fn print_summary(&self) {
+        match self {
+            DownloadResult::Downloaded { input, puzzle } => {
+                println!("🎄 Successfully wrote input to \"{}\".", input.display());
+                println!("🎄 Successfully wrote puzzle to \"{}\".", puzzle.display());
+            }
+            DownloadResult::NotUnlocked => {
+                println!("{ANSI_YELLOW}{ANSI_BOLD}⏳ This puzzle is not unlocked yet.{ANSI_RESET}");
+            }
+        }
+    }
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -48,10 +171,12 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    call_aoc_cli(&args)
+    let output = call_aoc_cli(&args)?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    Ok(output)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
+pub fn download(day: Day) -> Result<AocReply<DownloadResult>, AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
@@ -67,34 +192,72 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    let output = match call_aoc_cli(&args) {
+        Ok(output) | Err(AocCommandError::BadExitStatus(output)) => output,
+        Err(e) => return Err(e),
+    };
+
+    let text = output_text(&output);
+    match download_result(output.status.success(), &text, &input_path, &puzzle_path) {
+        Some(result) => Ok(AocReply { result, text }),
+        None => Err(AocCommandError::BadExitStatus(output)),
+    }
+}
+
+/// Reads the outcome of `aoc download` from its exit status and output, `None` if it failed otherwise.
+fn download_result(
+    success: bool,
+    text: &str,
+    input_path: &str,
+    puzzle_path: &str,
+) -> Option<DownloadResult> {
+    if success {
+        Some(DownloadResult::Downloaded {
+            input: input_path.into(),
+            puzzle: puzzle_path.into(),
+        })
+    } else if is_locked(text) {
+        Some(DownloadResult::NotUnlocked)
+    } else {
+        None
+    }
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(
+    day: Day,
+    part: u8,
+    result: &str,
+) -> Result<AocReply<SubmitVerdict>, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // aoc-cli exits with an error for some verdicts, the reply is parsed either way.
+    let output = match call_aoc_cli(&args) {
+        Ok(output) | Err(AocCommandError::BadExitStatus(output)) => output,
+        Err(e) => return Err(e),
+    };
+
+    let text = output_text(&output);
+    match SubmitVerdict::parse(&text) {
+        Some(result) => Ok(AocReply { result, text }),
+        None => Err(AocCommandError::BadExitStatus(output)),
+    }
 }
 
 fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+    let path = config::get().paths.day_file("inputs", format!("{day}.txt"));
+    path.to_string_lossy().into_owned()
 }
 
 fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    let path = config::get().paths.day_file("puzzles", format!("{day}.md"));
+    path.to_string_lossy().into_owned()
 }
 
 fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    config::get().year
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
@@ -114,8 +277,8 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
@@ -125,3 +288,108 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Everything aoc-cli printed, stdout first, without colors.
+fn output_text(output: &Output) -> String {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    strip_ansi(&format!("{stdout}{stderr}"))
+}
+
+fn strip_ansi(text: &str) -> String {
+    static ANSI: OnceLock<Regex> = OnceLock::new();
+    let ansi = ANSI.get_or_init(|| Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").unwrap());
+    ansi.replace_all(text, "").into_owned()
+}
+
+fn is_locked(text: &str) -> bool {
+    text.contains("before it unlocks")
+        || text.contains("not unlocked")
+        || text.contains("unlocks in")
+}
+
+/// Reads the wait time of a rate limited reply, e.g. `you have 1m 3s left to wait`.
+fn parse_wait(reply: &str) -> Option<Duration> {
+    static WAIT: OnceLock<Regex> = OnceLock::new();
+    let wait = WAIT.get_or_init(|| Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+    let captures = wait.captures(reply)?;
+    let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+    let seconds: u64 = captures[2].parse().ok()?;
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::{download_result, DownloadResult, Hint, SubmitVerdict};
+
+    #[test]
+    fn parses_submit_verdicts() {
+        let parse = |s: &str| SubmitVerdict::parse(s);
+
+        assert_eq!(
+            parse("\x1b[1mThat's the right answer!\x1b[0m You are one gold star closer."),
+            Some(SubmitVerdict::Correct)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low. Please wait one minute."),
+            Some(SubmitVerdict::Incorrect {
+                hint: Some(Hint::TooLow)
+            })
+        );
+        assert_eq!(
+            parse("That's not the right answer. If you're stuck, make sure you're using the full input data."),
+            Some(SubmitVerdict::Incorrect { hint: None })
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 3s left to wait."),
+            Some(SubmitVerdict::RateLimited {
+                wait: Some(Duration::from_secs(63))
+            })
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(SubmitVerdict::AlreadySolved)
+        );
+        assert_eq!(
+            parse("Please don't repeatedly request this endpoint before it unlocks!"),
+            Some(SubmitVerdict::NotUnlocked)
+        );
+        assert_eq!(parse("error: HTTP status client error (500)"), None);
+    }
+
+    #[test]
+    fn parses_download_results() {
+        let download = |success: bool, text: &str| {
+            download_result(success, text, "data/inputs/01.txt", "data/puzzles/01.md")
+        };
+
+        assert_eq!(
+            download(true, "Saved puzzle to 'data/puzzles/01.md'"),
+            Some(DownloadResult::Downloaded {
+                input: PathBuf::from("data/inputs/01.txt"),
+                puzzle: PathBuf::from("data/puzzles/01.md"),
+            })
+        );
+        assert_eq!(
+            download(
+                false,
+                "Error: Puzzle 25 of 2024 is still locked, it unlocks in 3h 2m"
+            ),
+            Some(DownloadResult::NotUnlocked)
+        );
+        assert_eq!(
+            download(
+                false,
+                "Please don't repeatedly request this endpoint before it unlocks!"
+            ),
+            Some(DownloadResult::NotUnlocked)
+        );
+        assert_eq!(
+            download(false, "error: HTTP status client error (500)"),
+            None
+        );
+    }
+}
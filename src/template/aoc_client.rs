@@ -0,0 +1,533 @@
+/// Module that talks to the Advent of Code website directly, without aoc-cli.
+/// Authenticates with the `session` cookie of a logged in browser, see [`session`].
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{config, write_answer};
+use crate::Day;
+
+/// The file aoc-cli reads the session cookie from, shared so both work with the same login.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    MissingUserAgent,
+    /// The site rejected the session cookie.
+    Unauthorized,
+    /// The puzzle does not exist (yet), it unlocks at midnight EST.
+    NotFound,
+    Status(u16),
+    Transport(String),
+    /// The page did not hold any puzzle text, e.g. the login page after an expired session.
+    MissingArticle,
+    IO(io::Error),
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401 | 403, _) => AocClientError::Unauthorized,
+            ureq::Error::Status(404, _) => AocClientError::NotFound,
+            ureq::Error::Status(code, _) => AocClientError::Status(code),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie, set AOC_SESSION or write it to ~/{SESSION_FILE}."
+            ),
+            AocClientError::MissingUserAgent => write!(
+                f,
+                "no user agent, set client.user_agent in aoc.toml to a way to contact you, e.g. the URL of this repository."
+            ),
+            AocClientError::Unauthorized => {
+                write!(f, "the session cookie was rejected, it may have expired.")
+            }
+            AocClientError::NotFound => write!(f, "the puzzle is not unlocked yet."),
+            AocClientError::Status(code) => write!(f, "the server responded with status {code}."),
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::MissingArticle => {
+                write!(
+                    f,
+                    "the response holds no puzzle text, is the session cookie valid?"
+                )
+            }
+            AocClientError::IO(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+/// The session cookie from the config or `AOC_SESSION`, falling back to the file aoc-cli uses.
+#[must_use]
+pub fn session() -> Option<String> {
+    if let Some(session) = &config::get().client.session {
+        return Some(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(Path::new(&home).join(SESSION_FILE)).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/// The description of a day and the answers the site accepted for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The puzzle parts as markdown, part two is only included once part one is solved.
+    pub description: String,
+    /// The accepted answers, in the order of the parts.
+    pub answers: Vec<String>,
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, user_agent: &str, session: &str, year: u16) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(user_agent)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// A client for the site, session and year of the project configuration.
+    pub fn from_config() -> Result<Self, AocClientError> {
+        let settings = &config::get().client;
+        let user_agent = settings
+            .user_agent
+            .as_deref()
+            .ok_or(AocClientError::MissingUserAgent)?;
+        let session = session().ok_or(AocClientError::MissingSession)?;
+        let year = config::get().year.unwrap_or_else(|| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            current_event_year(now.as_secs())
+        });
+
+        Ok(Self::new(&settings.base_url, user_agent, &session, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The puzzle input of `day`, exactly as served.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}/input", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// The description of `day` and the answers of its solved parts.
+    pub fn puzzle(&self, day: Day) -> Result<Puzzle, AocClientError> {
+        let response = self
+            .agent
+            .get(&self.day_url(day))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        let html = response.into_string()?;
+
+        Ok(Puzzle {
+            description: articles_to_markdown(&html)?,
+            answers: accepted_answers(&html),
+        })
+    }
+
+    /// Submits `answer` for a part and returns the reply of the site as markdown.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        articles_to_markdown(&response.into_string()?)
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn get_input_path(day: Day) -> PathBuf {
+    config::get().paths.day_file("inputs", format!("{day}.txt"))
+}
+
+fn get_puzzle_path(day: Day) -> PathBuf {
+    config::get().paths.day_file("puzzles", format!("{day}.md"))
+}
+
+/// Records the answers of the solved parts, so days solved before `data/answers` existed are backfilled.
+fn write_answers(day: Day, answers: &[String]) -> Result<(), AocClientError> {
+    for (part, answer) in (1..).zip(answers) {
+        let path = write_answer(day, part, answer)?;
+        println!(
+            "🎄 Recorded the answer of part {part} in \"{}\".",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Downloads the input and the puzzle description of `day` into the data folders.
+pub fn download(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_config()?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+    let puzzle = client.puzzle(day)?;
+
+    write_file(&input_path, &client.input(day)?)?;
+    write_file(&puzzle_path, &puzzle.description)?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    write_answers(day, &puzzle.answers)
+}
+
+/// Fetches the puzzle description of `day`, saves it and prints it to the terminal.
+pub fn read(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_config()?;
+    let puzzle = client.puzzle(day)?;
+    write_file(&get_puzzle_path(day), &puzzle.description)?;
+    println!("{}", puzzle.description);
+    write_answers(day, &puzzle.answers)
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<String, AocClientError> {
+    AocClient::from_config()?.submit(day, part, result)
+}
+
+/// The year of the latest event at `unix_secs`. Puzzles unlock at midnight EST,
+/// so the event only starts on the first of December in that timezone.
+#[must_use]
+pub fn current_event_year(unix_secs: u64) -> u16 {
+    let est_days = unix_secs.saturating_sub(5 * 3600) / 86400;
+
+    // days since 1970-01-01 to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = est_days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    let event = if month == 12 { year } else { year - 1 };
+    u16::try_from(event).unwrap_or(u16::MAX)
+}
+
+/// The answers below the parts of a solved puzzle, `Your puzzle answer was <code>11</code>.`
+fn accepted_answers(html: &str) -> Vec<String> {
+    html.split("Your puzzle answer was <code>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</code>"))
+        .map(|(answer, _)| answer.trim().to_string())
+        .collect()
+}
+
+/// Converts every `<article>` of a page to markdown, the site wraps puzzle parts and replies in them.
+fn articles_to_markdown(html: &str) -> Result<String, AocClientError> {
+    let articles: Vec<String> = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|article| {
+            let start = article.find('>')? + 1;
+            let end = article.find("</article>")?;
+            Some(html_to_markdown(&article[start..end]))
+        })
+        .collect();
+
+    if articles.is_empty() {
+        return Err(AocClientError::MissingArticle);
+    }
+
+    Ok(articles.join("\n\n") + "\n")
+}
+
+/// Converts the small subset of html used by puzzle descriptions to markdown.
+#[must_use]
+pub fn html_to_markdown(html: &str) -> String {
+    // highlighted answers are `<code><em>`, emphasis inside inline code would not render.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html.as_str();
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut markdown, &rest[..start], in_pre);
+
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2" | "p", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) if !in_pre => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("ul", true) => markdown.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut markdown, rest, in_pre);
+    markdown.trim().to_string()
+}
+
+/// Appends a text node, skipping the line breaks between block elements.
+fn push_text(markdown: &mut String, text: &str, in_pre: bool) {
+    if !in_pre && text.trim().is_empty() && text.contains('\n') {
+        return;
+    }
+
+    markdown.push_str(
+        &text
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&apos;", "'")
+            .replace("&nbsp;", " ")
+            .replace("&amp;", "&"),
+    );
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    use super::{
+        accepted_answers, current_event_year, html_to_markdown, AocClient, AocClientError,
+    };
+    use crate::day;
+
+    /// Serves one canned response per request on a local port and forwards each raw request.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body_buf = vec![0; content_length];
+                reader.read_exact(&mut body_buf).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body_buf));
+                tx.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    const PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief</em> is missing.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul><li>Pairs &amp; <a href="/2024/about">more</a>.</li></ul>
+<p>The total is <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1882714</code>.</p>
+</main></body></html>"#;
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, requests) = mock_server(vec![(200, "1 2\n3 4\n")]);
+        let client = AocClient::new(&base_url, "tests", "secret", 2024);
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=secret"));
+        assert!(request.to_lowercase().contains("user-agent: tests"));
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        let (base_url, _requests) = mock_server(vec![(200, PUZZLE)]);
+        let client = AocClient::new(&base_url, "tests", "secret", 2024);
+
+        assert_eq!(
+            client.puzzle(day!(1)).unwrap().description,
+            "## --- Day 1: Historian Hysteria ---\n\nThe *Chief* is missing.\n\nFor example:\n\n```\n3   4\n4   3\n```\n\n- Pairs & [more](/2024/about).\n\nThe total is *`11`*.\n"
+        );
+    }
+
+    #[test]
+    fn reads_accepted_answers() {
+        assert_eq!(accepted_answers(PUZZLE), ["1882714"]);
+        assert!(accepted_answers("<main><article></article></main>").is_empty());
+
+        let solved = "<p>Your puzzle answer was <code>11</code>.</p><p>Your puzzle answer was <code>31</code>.</p>";
+        assert_eq!(accepted_answers(solved), ["11", "31"]);
+    }
+
+    #[test]
+    fn submits_answer() {
+        let reply = "<main><article><p>That's the right answer!</p></article></main>";
+        let (base_url, requests) = mock_server(vec![(200, reply)]);
+        let client = AocClient::new(&base_url, "tests", "secret", 2024);
+
+        assert_eq!(
+            client.submit(day!(7), 2, "1234").unwrap(),
+            "That's the right answer!\n"
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn maps_errors() {
+        let (base_url, _requests) = mock_server(vec![
+            (400, "Please log in"),
+            (404, "Not found"),
+            (200, "<html></html>"),
+        ]);
+        let client = AocClient::new(&base_url, "tests", "expired", 2024);
+
+        assert!(matches!(
+            client.input(day!(1)),
+            Err(AocClientError::Unauthorized)
+        ));
+        assert!(matches!(
+            client.input(day!(25)),
+            Err(AocClientError::NotFound)
+        ));
+        assert!(matches!(
+            client.puzzle(day!(1)),
+            Err(AocClientError::MissingArticle)
+        ));
+    }
+
+    #[test]
+    fn finds_current_event() {
+        // 2024-11-30T12:00:00Z
+        assert_eq!(current_event_year(1_732_968_000), 2023);
+        // 2024-12-01T04:00:00Z is still November 30 in EST.
+        assert_eq!(current_event_year(1_733_025_600), 2023);
+        // 2024-12-01T05:00:00Z
+        assert_eq!(current_event_year(1_733_029_200), 2024);
+        // 2025-01-15T00:00:00Z
+        assert_eq!(current_event_year(1_736_899_200), 2024);
+    }
+
+    #[test]
+    fn converts_html() {
+        assert_eq!(html_to_markdown("<p>a &lt; b</p>\n<p>c</p>"), "a < b\n\nc");
+    }
+}
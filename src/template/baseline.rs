@@ -0,0 +1,305 @@
+/// Module that saves the timings of `all --time` as a named baseline and compares later runs against it.
+/// Baselines are stored as json in `data/baselines`, see [`config::Paths`].
+use std::{fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::{config, readme_benchmarks::Timings};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+use crate::Day;
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound(String),
+    /// Names become file names, so they are restricted to letters, digits, `-` and `_`.
+    InvalidName(String),
+    Parser(serde_json::Error),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound(name) => write!(
+                f,
+                "no baseline named `{name}`, save one with --save-baseline {name}"
+            ),
+            Error::InvalidName(name) => write!(
+                f,
+                "invalid baseline name `{name}`, use only letters, digits, `-` and `_`"
+            ),
+            Error::Parser(e) => write!(f, "failed to parse baseline: {e}"),
+            Error::IO(e) => write!(f, "failed to access baseline: {e}"),
+        }
+    }
+}
+
+/// Parses the name of a baseline passed to `--save-baseline` or `--baseline`.
+pub fn parse_name(name: &str) -> Result<String, Error> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if is_valid {
+        Ok(name.to_string())
+    } else {
+        Err(Error::InvalidName(name.to_string()))
+    }
+}
+
+pub fn get_path_for_baseline(name: &str) -> Result<PathBuf, Error> {
+    let name = parse_name(name)?;
+    Ok(config::get()
+        .paths
+        .day_file("baselines", format!("{name}.json")))
+}
+
+/// Saves `timings` under `name`. The days of a previous baseline of the same name that were not run
+/// are kept, so saving a subset of the days only replaces their timings.
+pub fn save(name: &str, timings: &[Timings]) -> Result<PathBuf, Error> {
+    let saved = match load(name) {
+        Err(Error::NotFound(_)) => vec![],
+        result => result?,
+    };
+
+    let path = get_path_for_baseline(name)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&merge(saved, timings))?)?;
+    Ok(path)
+}
+
+/// `saved` with the days of `timings` replaced or added, ordered by day.
+fn merge(saved: Vec<Timings>, timings: &[Timings]) -> Vec<Timings> {
+    let mut merged: Vec<Timings> = saved
+        .into_iter()
+        .filter(|previous| timings.iter().all(|timing| timing.day != previous.day))
+        .chain(timings.iter().cloned())
+        .collect();
+
+    merged.sort_by_key(|timing| timing.day);
+    merged
+}
+
+pub fn load(name: &str) -> Result<Vec<Timings>, Error> {
+    let path = get_path_for_baseline(name)?;
+    let content = fs::read_to_string(&path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::NotFound(name.into()),
+        _ => Error::IO(e),
+    })?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// The median time of a part in the baseline and in the current run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl Comparison {
+    /// The relative change in percent, positive when the part got slower.
+    #[must_use]
+    pub fn change_percent(&self) -> f64 {
+        if self.baseline_nanos == 0_f64 {
+            return 0_f64;
+        }
+        (self.current_nanos - self.baseline_nanos) / self.baseline_nanos * 100_f64
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    #[must_use]
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+/// Pairs the parts that were timed both in `baseline` and `current`, in the order of `current`.
+#[must_use]
+pub fn compare(baseline: &[Timings], current: &[Timings]) -> Vec<Comparison> {
+    let parts = |timings: &Timings| [(1, timings.part_1), (2, timings.part_2)];
+
+    current
+        .iter()
+        .filter_map(|timings| {
+            let previous = baseline
+                .iter()
+                .find(|previous| previous.day == timings.day)?;
+            Some((timings, previous))
+        })
+        .flat_map(|(timings, previous)| {
+            parts(timings).into_iter().zip(parts(previous)).filter_map(
+                |((part, current), (_, baseline))| match (baseline, current) {
+                    (Some(baseline), Some(current)) => Some(Comparison {
+                        day: timings.day,
+                        part,
+                        baseline_nanos: baseline.median_nanos,
+                        current_nanos: current.median_nanos,
+                    }),
+                    _ => None,
+                },
+            )
+        })
+        .collect()
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.abs() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Prints one row per compared part, regressions above `threshold` percent are highlighted in red,
+/// improvements of the same magnitude in green.
+pub fn print_comparison(name: &str, comparisons: &[Comparison], threshold: f64) {
+    eprintln!();
+    eprintln!("{ANSI_BOLD}Baseline `{name}`{ANSI_RESET} (regressions above {threshold}%)");
+    eprintln!();
+
+    if comparisons.is_empty() {
+        eprintln!("No parts in common with the baseline.");
+        return;
+    }
+
+    eprintln!(
+        "{:<8} {:<6} {:>12} {:>12} {:>12} {:>9}",
+        "Day", "Part", "Baseline", "Current", "Change", "%"
+    );
+
+    for comparison in comparisons {
+        let delta = comparison.current_nanos - comparison.baseline_nanos;
+        let sign = if delta < 0_f64 { "-" } else { "+" };
+        let percent = comparison.change_percent();
+
+        let color = if comparison.is_regression(threshold) {
+            ANSI_RED
+        } else if percent < -threshold {
+            ANSI_GREEN
+        } else {
+            ""
+        };
+
+        eprintln!(
+            "{color}{:<8} {:<6} {:>12} {:>12} {:>12} {:>+8.1}%{ANSI_RESET}",
+            format!("Day {}", comparison.day),
+            comparison.part,
+            format_nanos(comparison.baseline_nanos),
+            format_nanos(comparison.current_nanos),
+            format!("{sign}{}", format_nanos(delta)),
+            percent
+        );
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, merge, parse_name, Comparison};
+    use crate::day;
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+
+    fn timings(day: crate::Day, part_1: Option<f64>, part_2: Option<f64>) -> Timings {
+        let ms = |median: f64| PartTiming {
+            median_nanos: median * 1e6,
+            std_dev_nanos: 0_f64,
+            peak_bytes: None,
+        };
+        Timings {
+            day,
+            part_1: part_1.map(ms),
+            part_2: part_2.map(ms),
+            total_nanos: (part_1.unwrap_or(0_f64) + part_2.unwrap_or(0_f64)) * 1e6,
+        }
+    }
+
+    #[test]
+    fn compares_common_parts() {
+        let baseline = vec![
+            timings(day!(1), Some(10.0), Some(20.0)),
+            timings(day!(2), Some(5.0), None),
+        ];
+        let current = vec![
+            timings(day!(1), Some(12.0), Some(10.0)),
+            timings(day!(2), Some(5.0), Some(1.0)),
+            timings(day!(3), Some(1.0), Some(1.0)),
+        ];
+
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(comparisons.len(), 3);
+        assert_eq!((comparisons[0].day, comparisons[0].part), (day!(1), 1));
+        assert_eq!((comparisons[1].day, comparisons[1].part), (day!(1), 2));
+        assert_eq!((comparisons[2].day, comparisons[2].part), (day!(2), 1));
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let comparison = |baseline_nanos: f64, current_nanos: f64| Comparison {
+            day: day!(1),
+            part: 1,
+            baseline_nanos,
+            current_nanos,
+        };
+
+        assert!((comparison(100.0, 120.0).change_percent() - 20.0).abs() < 1e-9);
+        assert!(comparison(100.0, 120.0).is_regression(10.0));
+        assert!(!comparison(100.0, 105.0).is_regression(10.0));
+        assert!(!comparison(100.0, 50.0).is_regression(10.0));
+        assert!(!comparison(0.0, 50.0).is_regression(10.0));
+    }
+
+    #[test]
+    fn merges_partial_saves() {
+        let saved = vec![
+            timings(day!(1), Some(10.0), Some(20.0)),
+            timings(day!(3), Some(5.0), None),
+        ];
+        let current = vec![
+            timings(day!(3), Some(4.0), Some(1.0)),
+            timings(day!(2), Some(1.0), None),
+        ];
+
+        let merged = merge(saved, &current);
+
+        assert_eq!(
+            merged.iter().map(|timing| timing.day).collect::<Vec<_>>(),
+            [day!(1), day!(2), day!(3)]
+        );
+        assert_eq!(merged[0], timings(day!(1), Some(10.0), Some(20.0)));
+        assert_eq!(merged[2], current[0]);
+    }
+
+    #[test]
+    fn validates_names() {
+        assert_eq!(parse_name("before-fix_2").unwrap(), "before-fix_2");
+        assert!(parse_name("").is_err());
+        assert!(parse_name("../README").is_err());
+        assert!(parse_name("a/b").is_err());
+        assert!(parse_name("main.json").is_err());
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let saved = vec![timings(day!(7), Some(1.5), None)];
+        let json = serde_json::to_string(&saved).unwrap();
+        let loaded: Vec<Timings> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded, saved);
+        assert!(
+            serde_json::from_str::<Vec<Timings>>(&json.replace("\"day\":7", "\"day\":26")).is_err()
+        );
+    }
+}
@@ -1,39 +1,270 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{BufRead, BufReader},
+    process::{self, Command, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    baseline::{self, Comparison},
+    parse_timeout,
+    readme_benchmarks::{self, PartTiming, Timings},
+    runner::{self, DaySolution, PartReport, PartStatus, RunOptions},
+    timeout_arg, InputSource, OutputFormat, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DaySet};
+
+/// Starts a worker process of the `all` binary for a single day, see [`run_isolated`].
+const WORKER_FLAG: &str = "--worker";
+
+/// What to do with the timings of a timed run, see [`baseline`].
+#[derive(Debug, Clone, Default)]
+pub struct BaselineOptions {
+    /// Save the timings under this name.
+    pub save: Option<String>,
+    /// Compare the timings with the baseline of this name.
+    pub compare: Option<String>,
+    /// Slowdown in percent above which a part counts as a regression.
+    pub threshold: f64,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+/// The resolved arguments of `all`, passed from the main binary to the `all` binary.
+#[derive(Debug, Clone)]
+pub struct AllOptions {
+    pub days: DaySet,
+    /// Build in release mode and update the README benchmarks of a timed run.
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+    pub baseline: BaselineOptions,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
+impl AllOptions {
+    /// The arguments read by [`AllOptions::from_args`].
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--days".to_string(),
+            self.days.to_string(),
+            "--format".to_string(),
+            self.format.to_string(),
+            "--jobs".to_string(),
+            self.jobs.to_string(),
+            "--threshold".to_string(),
+            self.baseline.threshold.to_string(),
+            "--timeout".to_string(),
+            timeout_arg(self.timeout),
+        ];
+
+        if self.is_release {
+            args.push("--release".to_string());
         }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+        if self.is_timed {
+            args.push("--time".to_string());
+        }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        if let Some(name) = &self.baseline.save {
+            args.push("--save-baseline".to_string());
+            args.push(name.clone());
+        }
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+        if let Some(name) = &self.baseline.compare {
+            args.push("--baseline".to_string());
+            args.push(name.clone());
         }
+
+        args
+    }
+
+    /// Parse the arguments passed to the `all` binary, see [`AllOptions::to_args`].
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            days: args.value_from_str("--days")?,
+            is_release: args.contains("--release"),
+            is_timed: args.contains("--time"),
+            format: args.value_from_str("--format")?,
+            timeout: args.value_from_fn("--timeout", parse_timeout)?,
+            jobs: args.value_from_str("--jobs")?,
+            baseline: BaselineOptions {
+                save: args.opt_value_from_fn("--save-baseline", baseline::parse_name)?,
+                compare: args.opt_value_from_fn("--baseline", baseline::parse_name)?,
+                threshold: args.value_from_str("--threshold")?,
+            },
+        })
+    }
+}
+
+/// Builds and runs the `all` binary, which has every scaffolded day compiled in, see `build.rs`.
+/// The days are kept out of the main binary, so a day that does not compile only breaks `all`.
+pub fn handle(options: &AllOptions) {
+    if options.days.is_empty() {
+        eprintln!("No days selected.");
+        return;
+    }
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--quiet".to_string(),
+        "--bin".to_string(),
+        "all".to_string(),
+    ];
+
+    if options.is_release {
+        cmd_args.push("--release".to_string());
+    }
+
+    // the days only count allocations if they are built the same way.
+    if cfg!(feature = "alloc_stats") {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_stats".to_string());
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.extend(options.to_args());
+
+    match Command::new("cargo").args(&cmd_args).status() {
+        Ok(status) if status.success() => {}
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs the selected days of `solutions` in-process, this is the `main` function of the `all` binary.
+/// With `jobs > 1` days run concurrently, their output is buffered and printed in day order.
+pub fn run(solutions: &[DaySolution], options: &AllOptions) {
+    let AllOptions {
+        days,
+        is_release,
+        is_timed,
+        format,
+        timeout,
+        jobs,
+        baseline: ref baseline_options,
+    } = *options;
+
+    let mut timings: Vec<Timings> = vec![];
+    let mut failures: Vec<PartReport> = vec![];
+    let mut regressions: Vec<Comparison> = vec![];
+
+    // fail before benchmarking, not after.
+    let saved_baseline = baseline_options.compare.as_ref().map(|name| {
+        baseline::load(name).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
     });
 
+    let run_options = RunOptions {
+        is_timed,
+        timeout,
+        check_answers: true,
+        show_progress: false,
+        part: None,
+    };
+
+    if days.is_empty() {
+        eprintln!("No days selected.");
+        return;
+    }
+
+    // benchmarks running side by side would compete for the same cores.
+    if is_timed && jobs > 1 {
+        eprintln!("Note: --time runs days sequentially, ignoring --jobs.");
+    }
+
+    let mut on_day = |day: Day, reports: Vec<PartReport>| {
+        if !reports.is_empty() {
+            failures.extend(
+                reports
+                    .iter()
+                    .filter(|report| {
+                        matches!(
+                            report.status,
+                            PartStatus::Incorrect | PartStatus::TimedOut | PartStatus::Panicked
+                        )
+                    })
+                    .cloned(),
+            );
+
+            let val = collect_timings(&reports, day);
+            timings.push(val);
+        }
+    };
+
+    let first_day = days.iter().next();
+
+    if is_timed || jobs <= 1 {
+        days.iter().for_each(|day| {
+            print_header(day, Some(day) == first_day, format);
+            let reports = run_solution(solutions, day, &run_options, &mut |report| {
+                print_report(report, format);
+            });
+            print_footer(&reports, format);
+            on_day(day, reports);
+        });
+    } else {
+        run_parallel(solutions, days, &run_options, jobs, |day, reports| {
+            print_header(day, Some(day) == first_day, format);
+            reports.iter().for_each(|report| print_report(report, format));
+            print_footer(&reports, format);
+            on_day(day, reports);
+        });
+    }
+
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if format == OutputFormat::Text {
+            println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        }
 
-        if is_release {
+        if let (Some(name), Some(saved)) = (&baseline_options.compare, &saved_baseline) {
+            let comparisons = baseline::compare(saved, &timings);
+            baseline::print_comparison(name, &comparisons, baseline_options.threshold);
+            regressions.extend(
+                comparisons
+                    .into_iter()
+                    .filter(|comparison| comparison.is_regression(baseline_options.threshold)),
+            );
+        }
+
+        if let Some(name) = &baseline_options.save {
+            // same as the README, a baseline of wrong answers is worthless.
+            if failures.is_empty() {
+                match baseline::save(name, &timings) {
+                    Ok(path) => eprintln!("Saved baseline `{name}` to {}.", path.display()),
+                    Err(e) => eprintln!("Failed to save baseline: {e}"),
+                }
+            } else {
+                eprintln!("Skipped saving baseline `{name}`, some parts failed.");
+            }
+        }
+
+        if is_release && cfg!(debug_assertions) {
+            // `--release` builds this binary in release mode, unless it was started some other way.
+            eprintln!("Skipped updating README, benchmarks are only published from a release build.");
+        } else if is_release && days != DaySet::all() {
+            // the table lists every day, a selection would drop the others from it.
+            eprintln!("Skipped updating README, benchmarks are only published when running every day.");
+        } else if is_release && failures.is_empty() {
+            // do not publish benchmarks of solutions that no longer produce the right answers.
             match readme_benchmarks::update(timings, total_millis) {
+                // keep stdout machine-readable in json mode.
+                Ok(()) if format == OutputFormat::Json => {
+                    eprintln!("Successfully updated README with benchmarks.");
+                }
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
@@ -41,214 +272,378 @@ pub fn handle(is_release: bool, is_timed: bool) {
             }
         }
     }
-}
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
-}
+    let has_failed = !failures.is_empty() || !regressions.is_empty();
+
+    if !failures.is_empty() {
+        eprintln!();
+        eprintln!("{ANSI_BOLD}✖ {} part(s) failed:{ANSI_RESET}", failures.len());
+        for report in failures {
+            let part = match report.part {
+                0 => "Parse".to_string(),
+                part => format!("Part {part}"),
+            };
+            let reason = match report.status {
+                PartStatus::TimedOut => "timed out".to_string(),
+                PartStatus::Panicked => {
+                    format!("panicked: {}", report.error.unwrap_or_default())
+                }
+                _ => format!(
+                    "expected {}, got {}",
+                    report.expected.unwrap_or_default(),
+                    report.answer.unwrap_or_else(|| "nothing".into())
+                ),
+            };
+            eprintln!("  Day {:02} {part}: {reason}", report.day);
+        }
+    }
+
+    if !regressions.is_empty() {
+        eprintln!();
+        eprintln!(
+            "{ANSI_BOLD}✖ {} part(s) regressed by more than {}%:{ANSI_RESET}",
+            regressions.len(),
+            baseline_options.threshold
+        );
+        for comparison in &regressions {
+            eprintln!(
+                "  Day {} Part {}: {:+.1}%",
+                comparison.day,
+                comparison.part,
+                comparison.change_percent()
+            );
+        }
+    }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+    if has_failed {
+        process::exit(1);
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Run the solution of a given day against its puzzle input, passing the reports to `on_report` as they arrive.
+/// Returns no reports if the day has not been scaffolded or has no input.
+///
+/// With a timeout, the day runs in a worker process, see [`run_worker`].
+fn run_solution(
+    solutions: &[DaySolution],
+    day: Day,
+    options: &RunOptions,
+    on_report: &mut dyn FnMut(&PartReport),
+) -> Vec<PartReport> {
+    if options.timeout.is_some() {
+        return run_isolated(day, options, on_report);
+    }
+
+    run_in_process(solutions, day, options, on_report)
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+fn run_in_process(
+    solutions: &[DaySolution],
+    day: Day,
+    options: &RunOptions,
+    on_report: &mut dyn FnMut(&PartReport),
+) -> Vec<PartReport> {
+    let Some(solution) = solutions.iter().find(|solution| solution.day == day) else {
+        return vec![];
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    let Ok(input) = InputSource::Puzzle.try_read(day) else {
+        return vec![];
+    };
+
+    // Hate windows
+    // parts run on watchdog threads, which need to own their input.
+    let input: &'static str = Box::leak(input.replace('\r', "").into_boxed_str());
+
+    let mut reports = vec![];
+
+    (solution.run)(input, options, &mut |report| {
+        on_report(report);
+        reports.push(report.clone());
+    });
+
+    reports
+}
+
+/// Runs `day` in a worker process of this binary and collects the reports it prints.
+/// A part that timed out keeps running on its abandoned thread until its worker exits,
+/// which would otherwise skew the timings of every later day or run out of memory.
+fn run_isolated(
+    day: Day,
+    options: &RunOptions,
+    on_report: &mut dyn FnMut(&PartReport),
+) -> Vec<PartReport> {
+    let mut args = vec![WORKER_FLAG.to_string(), day.to_string()];
+
+    if options.is_timed {
+        args.push("--time".to_string());
+    }
+
+    args.push("--timeout".to_string());
+    args.push(timeout_arg(options.timeout));
+
+    let worker = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+    });
+
+    let mut worker = match worker {
+        Ok(worker) => worker,
+        Err(e) => {
+            eprintln!("Failed to start the worker of day {day}: {e}");
+            return vec![];
         }
+    };
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    let mut reports = vec![];
 
-        if is_release {
-            args.push("--release");
+    if let Some(stdout) = worker.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(report) = PartReport::from_json(&line) {
+                on_report(&report);
+                reports.push(report);
+            }
         }
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    match worker.wait() {
+        Ok(status) if !status.success() => {
+            eprintln!("The worker of day {day} exited with {status}.");
         }
+        Err(e) => eprintln!("Failed to wait for the worker of day {day}: {e}"),
+        Ok(_) => {}
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    reports
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+/// Parse the arguments of a worker process started by [`run_isolated`], `None` if this is not a worker.
+pub fn parse_worker(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<(Day, RunOptions)>, pico_args::Error> {
+    let Some(day) = args.opt_value_from_str(WORKER_FLAG)? else {
+        return Ok(None);
+    };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    let options = RunOptions {
+        is_timed: args.contains("--time"),
+        timeout: args.value_from_fn("--timeout", parse_timeout)?,
+        check_answers: true,
+        show_progress: false,
+        part: None,
+    };
+
+    Ok(Some((day, options)))
+}
 
-        let mut output = vec![];
+/// Runs a single day and prints its reports as json lines, read by [`run_isolated`].
+/// Exiting the process stops the parts that timed out.
+pub fn run_worker(solutions: &[DaySolution], day: Day, options: &RunOptions) {
+    run_in_process(solutions, day, options, &mut |report| {
+        println!("{}", report.to_json());
+    });
+}
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+/// Run `days` on a pool of `jobs` workers, handing each day's reports to `on_day` in day order.
+fn run_parallel(
+    solutions: &[DaySolution],
+    days: DaySet,
+    options: &RunOptions,
+    jobs: usize,
+    mut on_day: impl FnMut(Day, Vec<PartReport>),
+) {
+    let days: Vec<Day> = days.iter().collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let (days, next) = (&days, &next);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let reports = run_solution(solutions, day, options, &mut |_| {});
+                if tx.send((index, reports)).is_err() {
+                    break;
+                }
             });
-        });
+        }
+        drop(tx);
+
+        // days finish out of order, hold on to them until all earlier days have been printed.
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for (index, reports) in rx {
+            pending.insert(index, reports);
+            while let Some(reports) = pending.remove(&printed) {
+                on_day(days[printed], reports);
+                printed += 1;
+            }
+        }
+    });
+}
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+fn print_header(day: Day, is_first: bool, format: OutputFormat) {
+    if format == OutputFormat::Text {
+        if !is_first {
+            println!();
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+}
 
-        Ok(output)
+fn print_report(report: &PartReport, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => runner::print_report(report),
+        OutputFormat::Json => println!("{}", report.to_json()),
     }
+}
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+fn print_footer(reports: &[PartReport], format: OutputFormat) {
+    if reports.is_empty() && format == OutputFormat::Text {
+        println!("Not solved.");
+    }
+}
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+fn collect_timings(reports: &[PartReport], day: Day) -> Timings {
+    let mut timings = Timings {
+        day,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+    };
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    // the parse step of a `Solution` counts towards the total, but has no column of its own.
+    reports
+        .iter()
+        .filter(|report| report.answer.is_some() || report.status == PartStatus::Parsed)
+        .for_each(|report| {
+            #[allow(clippy::cast_precision_loss)]
+            let timing = PartTiming {
+                median_nanos: report.stats.median.as_nanos() as f64,
+                std_dev_nanos: report.stats.std_dev.as_nanos() as f64,
+                peak_bytes: report.stats.alloc.map(|alloc| alloc.peak_bytes),
+            };
+
+            match report.part {
+                1 => timings.part_1 = Some(timing),
+                2 => timings.part_2 = Some(timing),
+                _ => {}
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            timings.total_nanos += timing.median_nanos;
+        });
 
-                timings.total_nanos += nanos;
-            });
+    timings
+}
+
+/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+#[cfg(feature = "test_lib")]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            (*a - *b).abs() < 1.0e-6,
+            "{} is not approximately equal to {}",
+            *a,
+            *b
+        );
+    }};
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::collect_timings;
+    use std::time::Duration;
 
-        timings
+    use crate::day;
+    use crate::template::runner::{BenchStats, PartReport};
+
+    fn report(line: &str) -> PartReport {
+        PartReport::from_json(line).unwrap()
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[test]
+    fn test_well_formed() {
+        let res = collect_timings(
+            &[
+                report(r#"{"day":1,"part":1,"status":"solved","answer":"0","stats":{"samples":100000,"min_ns":70,"median_ns":74,"p95_ns":80,"max_ns":120,"std_dev_ns":2}}"#),
+                report(r#"{"day":1,"part":2,"status":"solved","answer":"10","stats":{"samples":99999,"min_ns":74000000,"median_ns":74130000,"p95_ns":75000000,"max_ns":76000000,"std_dev_ns":0}}"#),
+            ],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 74130074_f64);
+        assert_approx_eq!(res.part_1.unwrap().median_nanos, 74_f64);
+        assert_approx_eq!(res.part_1.unwrap().std_dev_nanos, 2_f64);
+        assert_approx_eq!(res.part_2.unwrap().median_nanos, 74130000_f64);
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
+    #[test]
+    fn test_patterns_in_answer() {
+        let answer = "@ @ @ ( ) ms (2s @ 5 samples)\nPart 2: 1 (1ns @ 1 samples)";
+        let stats = BenchStats::single(Duration::from_secs(2));
+        let line = PartReport::new(day!(1), 1, Some(&answer), stats).to_json();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        let parsed = report(&line);
+        assert_eq!(parsed.answer.as_deref(), Some(answer));
 
-        Some((str_timing, parsed_timing))
+        let res = collect_timings(&[parsed], day!(1));
+        assert_approx_eq!(res.total_nanos, 2e9_f64);
+        assert_approx_eq!(res.part_1.unwrap().median_nanos, 2e9_f64);
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_missing_parts() {
+        let res = collect_timings(
+            &[
+                report(r#"{"day":1,"part":1,"status":"unsolved","answer":null,"stats":{"samples":1,"min_ns":5,"median_ns":5,"p95_ns":5,"max_ns":5,"std_dev_ns":0}}"#),
+                report(r#"{"day":1,"part":2,"status":"unsolved","answer":null,"stats":{"samples":1,"min_ns":5,"median_ns":5,"p95_ns":5,"max_ns":5,"std_dev_ns":0}}"#),
+            ],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+    #[test]
+    fn test_parse_step() {
+        let res = collect_timings(
+            &[
+                PartReport::parse_step(day!(1), BenchStats::single(Duration::from_millis(3))),
+                PartReport::new(day!(1), 1, Some(&1), BenchStats::single(Duration::from_millis(1))),
+            ],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 4e6_f64);
+        assert_approx_eq!(res.part_1.unwrap().median_nanos, 1e6_f64);
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-        use crate::day;
+    #[test]
+    fn test_alloc_stats() {
+        let res = collect_timings(
+            &[report(
+                r#"{"day":1,"part":1,"status":"solved","answer":"1","stats":{"samples":1,"min_ns":1000,"median_ns":1000,"p95_ns":1000,"max_ns":1000,"std_dev_ns":0,"alloc":{"allocations":3,"bytes":3072,"peak_bytes":2048}}}"#,
+            )],
+            day!(1),
+        );
+        assert_eq!(res.part_1.unwrap().peak_bytes, Some(2048));
+        assert_eq!(res.part_1.unwrap().to_string(), "1.0µs · 2.0 KiB");
+    }
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
-
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_non_report_lines() {
+        assert_eq!(PartReport::from_json("Part 1: 0 (74.13ns @ 100000 samples)"), None);
+        assert_eq!(PartReport::from_json("42"), None);
     }
 }
@@ -0,0 +1,215 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    config::{self, Paths},
+    encryption, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+
+const HOOK: &str = "#!/bin/sh
+# refuses commits that stage plaintext inputs or puzzles, see `cargo check-inputs --help`.
+exec cargo check-inputs
+";
+
+/// Fails if a plaintext input or puzzle description is staged for the next commit.
+/// Plaintext files that `.gitignore` does not cover are reported too, `git add -A` would stage them.
+/// `install_hook` runs this check before every commit instead.
+pub fn handle(install_hook: bool) {
+    let result = if install_hook {
+        install()
+    } else {
+        check(Path::new("."), &config::get().paths)
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Checks the repository that `dir` belongs to.
+fn check(dir: &Path, paths: &Paths) -> Result<(), String> {
+    let root = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?.trim());
+
+    // folders outside of the repository, e.g. a configured `paths.inputs`, cannot be staged.
+    let folders: Vec<PathBuf> = encryption::FOLDERS
+        .iter()
+        .filter_map(|folder| fs::canonicalize(paths.dir(folder)).ok())
+        .filter(|dir| dir.starts_with(&root))
+        .collect();
+
+    if folders.is_empty() {
+        println!("{ANSI_GREEN}✔ No plaintext inputs are staged.{ANSI_RESET}");
+        return Ok(());
+    }
+
+    let mut args = vec![
+        "diff",
+        "--cached",
+        "--name-only",
+        "--diff-filter=d",
+        "-z",
+        "--",
+    ];
+    let pathspecs: Vec<String> = folders.iter().map(|dir| path_arg(dir)).collect();
+    args.extend(pathspecs.iter().map(String::as_str));
+
+    let staged: Vec<String> = git(&root, &args)?
+        .split('\0')
+        .filter(|path| !path.is_empty() && encryption::is_plaintext(Path::new(path)))
+        .map(ToString::to_string)
+        .collect();
+
+    let plaintext: Vec<String> = encryption::files_in(paths, false)
+        .iter()
+        .filter_map(|path| fs::canonicalize(path).ok())
+        .filter(|path| folders.iter().any(|dir| path.starts_with(dir)))
+        .map(|path| path_arg(&path))
+        .collect();
+
+    let not_ignored = if plaintext.is_empty() {
+        vec![]
+    } else {
+        let paths: String = plaintext.iter().map(|path| format!("{path}\0")).collect();
+        let ignored = git_with_input(&root, &["check-ignore", "--stdin", "-z"], &paths)?;
+        let ignored: Vec<&str> = ignored.split('\0').collect();
+
+        plaintext
+            .iter()
+            .filter(|path| !ignored.contains(&path.as_str()))
+            .map(|path| {
+                Path::new(path)
+                    .strip_prefix(&root)
+                    .map_or_else(|_| path.clone(), |p| p.display().to_string())
+            })
+            .collect()
+    };
+
+    if !not_ignored.is_empty() {
+        println!("{ANSI_YELLOW}{ANSI_BOLD}Plaintext files not covered by .gitignore:{ANSI_RESET}");
+        for path in &not_ignored {
+            println!("  {path}");
+        }
+        println!();
+    }
+
+    if staged.is_empty() {
+        println!("{ANSI_GREEN}✔ No plaintext inputs are staged.{ANSI_RESET}");
+        return Ok(());
+    }
+
+    println!("{ANSI_RED}{ANSI_BOLD}✖ Plaintext inputs are staged:{ANSI_RESET}");
+    for path in &staged {
+        println!("  {path}");
+    }
+
+    Err(format!(
+        "Run `cargo encrypt-inputs` and unstage them with `git restore --staged {}`.",
+        staged.join(" ")
+    ))
+}
+
+fn install() -> Result<(), String> {
+    let path = PathBuf::from(
+        git(
+            Path::new("."),
+            &["rev-parse", "--git-path", "hooks/pre-commit"],
+        )?
+        .trim(),
+    );
+
+    if path.exists() {
+        return Err(format!(
+            "{} already exists, add `cargo check-inputs` to it.",
+            path.display()
+        ));
+    }
+
+    let write = || -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, HOOK)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    };
+
+    write().map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    println!("Installed the pre-commit hook \"{}\"", path.display());
+    Ok(())
+}
+
+fn path_arg(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// Runs git in `dir` and returns its output, `check-ignore` exits with 1 if no path is ignored.
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    git_with_input(dir, args, "")
+}
+
+fn git_with_input(dir: &Path, args: &[&str], input: &str) -> Result<String, String> {
+    let mut child = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to call git: {e}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("failed to call git: {e}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to call git: {e}"))?;
+
+    if output.status.success() || output.status.code() == Some(1) {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(format!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{check, git};
+    use crate::template::encryption::{encrypted_path, TempData};
+
+    #[test]
+    fn refuses_staged_plaintext() {
+        let data = TempData::new("check-inputs");
+        let root = &data.paths.data;
+        let path = data.file("inputs", "01.txt");
+        fs::write(&path, "3   4\n").unwrap();
+        fs::write(encrypted_path(&path), "").unwrap();
+        fs::write(root.join(".gitignore"), "inputs/*.txt\n").unwrap();
+
+        git(root, &["init", "-q"]).unwrap();
+        git(root, &["add", "-A"]).unwrap();
+        assert_eq!(check(root, &data.paths), Ok(()));
+
+        git(root, &["add", "-f", "inputs/01.txt"]).unwrap();
+        let e = check(root, &data.paths).unwrap_err();
+        assert!(e.contains("inputs/01.txt"));
+    }
+}
@@ -0,0 +1,243 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::template::usage::{CommandUsage, Flag, COMMANDS};
+
+/// The name the completion scripts register for, i.e. the main binary.
+const BIN: &str = "advent_of_code";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err(format!(
+                "unsupported shell \"{s}\", expected bash, zsh or fish"
+            )),
+        }
+    }
+}
+
+impl Display for Shell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        })
+    }
+}
+
+/// Prints the completion script of `shell` to stdout, e.g. `cargo completions fish > ~/.config/fish/completions/advent_of_code.fish`.
+pub fn handle(shell: Shell) {
+    print!("{}", script(shell));
+}
+
+#[must_use]
+pub fn script(shell: Shell) -> String {
+    match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+    }
+}
+
+fn command_names() -> String {
+    COMMANDS
+        .iter()
+        .map(|command| command.name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bash() -> String {
+    let mut script = format!(
+        "_{BIN}() {{
+    local cur prev
+    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"
+    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"
+
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))
+        return
+    fi
+
+    case \"${{COMP_WORDS[1]}}\" in
+",
+        command_names()
+    );
+
+    for command in COMMANDS {
+        let flags: Vec<&str> = command.flags.iter().map(|flag| flag.name).collect();
+        script += &format!("        {})\n", command.name);
+        script += "            case \"$prev\" in\n";
+        for flag in command.flags.iter().filter(|flag| flag.value.is_some()) {
+            // values without fixed choices complete to files, which suits paths and is harmless otherwise.
+            let reply = if flag.choices.is_empty() {
+                "COMPREPLY=($(compgen -f -- \"$cur\"))".to_string()
+            } else {
+                format!(
+                    "COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))",
+                    flag.choices.join(" ")
+                )
+            };
+            script += &format!("                {}) {reply}; return ;;\n", flag.name);
+        }
+        script += "            esac\n";
+        if command.name == "completions" {
+            script += "            COMPREPLY=($(compgen -W \"bash zsh fish\" -- \"$cur\"))\n";
+        } else {
+            script += &format!(
+                "            COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))\n",
+                flags.join(" ")
+            );
+        }
+        script += "            ;;\n";
+    }
+
+    script += &format!("    esac\n}}\n\ncomplete -F _{BIN} {BIN}\n");
+    script
+}
+
+/// Escapes the characters `_arguments` and `_describe` treat specially in descriptions.
+fn zsh_escape(s: &str) -> String {
+    s.replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace(':', "\\:")
+}
+
+fn zsh_flag(flag: &Flag) -> String {
+    let value = match flag.value {
+        None => String::new(),
+        Some(value) if flag.choices.is_empty() => format!(":{}:_files", zsh_escape(value)),
+        Some(value) => format!(":{}:({})", zsh_escape(value), flag.choices.join(" ")),
+    };
+    format!("'{}[{}]{value}'", flag.name, zsh_escape(flag.help))
+}
+
+fn zsh_arguments(command: &CommandUsage) -> String {
+    let mut specs: Vec<String> = command.flags.iter().map(zsh_flag).collect();
+    if command.name == "completions" {
+        specs.push("':shell:(bash zsh fish)'".into());
+    }
+    if specs.is_empty() {
+        return ":".into();
+    }
+    format!(
+        "_arguments \\\n                {}",
+        specs.join(" \\\n                ")
+    )
+}
+
+fn zsh() -> String {
+    let mut script = format!(
+        "#compdef {BIN}
+
+_{BIN}() {{
+    local -a commands
+    commands=(
+"
+    );
+
+    for command in COMMANDS {
+        script += &format!("        '{}:{}'\n", command.name, zsh_escape(command.about));
+    }
+
+    script += "    )
+
+    if (( CURRENT == 2 )); then
+        _describe 'command' commands
+        return
+    fi
+
+    local command=$words[2]
+    shift words
+    (( CURRENT-- ))
+
+    case $command in
+";
+
+    for command in COMMANDS {
+        script += &format!(
+            "        {})\n            {}\n            ;;\n",
+            command.name,
+            zsh_arguments(command)
+        );
+    }
+
+    script += &format!("    esac\n}}\n\n_{BIN} \"$@\"\n");
+    script
+}
+
+fn fish_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn fish() -> String {
+    let mut script = format!("complete -c {BIN} -f\n");
+
+    for command in COMMANDS {
+        script += &format!(
+            "complete -c {BIN} -n __fish_use_subcommand -a {} -d '{}'\n",
+            command.name,
+            fish_escape(command.about)
+        );
+    }
+
+    for command in COMMANDS {
+        let condition = format!("'__fish_seen_subcommand_from {}'", command.name);
+        for flag in command.flags {
+            let value = match flag.value {
+                None => String::new(),
+                Some(_) if flag.choices.is_empty() => " -r -F".into(),
+                Some(_) => format!(" -x -a '{}'", flag.choices.join(" ")),
+            };
+            script += &format!(
+                "complete -c {BIN} -n {condition} -l {}{value} -d '{}'\n",
+                flag.name.trim_start_matches("--"),
+                fish_escape(flag.help)
+            );
+        }
+    }
+
+    script += &format!(
+        "complete -c {BIN} -n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'\n"
+    );
+    script
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{script, Shell};
+    use crate::template::usage::COMMANDS;
+
+    #[test]
+    fn completes_every_command_and_flag() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = script(shell);
+            for command in COMMANDS {
+                assert!(script.contains(command.name), "{shell}: {}", command.name);
+                for flag in command.flags {
+                    let name = flag.name.trim_start_matches("--");
+                    assert!(script.contains(name), "{shell}: {}", flag.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_shell() {
+        assert_eq!("zsh".parse::<Shell>(), Ok(Shell::Zsh));
+        assert!("powershell".parse::<Shell>().is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::encryption::{self, Key};
+
+/// Writes the plaintext of every encrypted input and puzzle description, e.g. on a fresh clone.
+/// Plaintext files that differ from their encrypted counterpart are kept, unless `force` is set.
+pub fn handle(force: bool) {
+    let key = match Key::from_config() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    match decrypt(&key, &encryption::files(true), force) {
+        Ok(unchanged) if unchanged > 0 => {
+            println!("{unchanged} file(s) were already decrypted.");
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Decrypts the `encrypted_files`, returns how many were skipped because they were unchanged.
+fn decrypt(key: &Key, encrypted_files: &[PathBuf], force: bool) -> Result<usize, String> {
+    let mut unchanged = 0;
+
+    for encrypted_path in encrypted_files {
+        let Some(path) = encryption::plaintext_path(encrypted_path) else {
+            continue;
+        };
+
+        let plaintext = key
+            .decrypt_file(encrypted_path)
+            .map_err(|e| e.to_string())?;
+
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == plaintext => {
+                unchanged += 1;
+                continue;
+            }
+            Ok(existing) if !existing.is_empty() && !force => {
+                println!(
+                    "Kept \"{}\", it differs from the encrypted file. Pass --force to overwrite it.",
+                    path.display()
+                );
+                continue;
+            }
+            _ => {}
+        }
+
+        fs::write(&path, plaintext)
+            .map_err(|e| format!("Failed to write \"{}\": {e}", path.display()))?;
+        println!("🔓 Decrypted \"{}\"", encrypted_path.display());
+    }
+
+    Ok(unchanged)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::decrypt;
+    use crate::template::encryption::{encrypted_path, files_in, Key, TempData};
+
+    #[test]
+    fn skips_unchanged_files() {
+        let data = TempData::new("decrypt-inputs");
+        let key = Key::parse(&Key::generate()).unwrap();
+        let path = data.file("inputs", "01.txt");
+        fs::write(encrypted_path(&path), key.encrypt(b"3   4\n")).unwrap();
+
+        assert_eq!(decrypt(&key, &files_in(&data.paths, true), false), Ok(0));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n");
+
+        assert_eq!(decrypt(&key, &files_in(&data.paths, true), false), Ok(1));
+
+        fs::write(&path, "4   3\n").unwrap();
+        assert_eq!(decrypt(&key, &files_in(&data.paths, true), false), Ok(0));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "4   3\n");
+
+        assert_eq!(decrypt(&key, &files_in(&data.paths, true), true), Ok(0));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n");
+    }
+
+    #[test]
+    fn refuses_wrong_keys() {
+        let data = TempData::new("decrypt-wrong-key");
+        let key = Key::parse(&Key::generate()).unwrap();
+        let other = Key::parse(&Key::generate()).unwrap();
+        let path = data.file("inputs", "01.txt");
+        fs::write(encrypted_path(&path), key.encrypt(b"3   4\n")).unwrap();
+
+        let e = decrypt(&other, &files_in(&data.paths, true), false).unwrap_err();
+        assert!(e.starts_with("could not decrypt"));
+        assert!(!path.exists());
+    }
+}
@@ -1,15 +1,27 @@
-use crate::template::aoc_cli;
+use crate::template::{aoc_cli, aoc_client};
 use crate::Day;
 use std::process;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    // without a session cookie, fall back to aoc-cli which keeps its own.
+    if aoc_client::session().is_none() && aoc_cli::check().is_ok() {
+        match aoc_cli::download(day) {
+            Ok(reply) => {
+                reply.result.print_summary();
+                if reply.result == aoc_cli::DownloadResult::NotUnlocked {
+                    process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+        }
+        return;
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = aoc_client::download(day) {
+        eprintln!("failed to download day {day}: {e}");
         process::exit(1);
     };
 }
@@ -0,0 +1,87 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::encryption::{self, Error, Key};
+
+/// Writes `{file}.enc` for every input and puzzle description, so they can be committed.
+/// Files whose encrypted counterpart already holds the same text are skipped, which keeps the diff quiet.
+pub fn handle() {
+    let key = match Key::from_config() {
+        Ok(key) => key,
+        Err(Error::MissingKey) => {
+            eprintln!("{}", Error::MissingKey);
+            eprintln!(
+                "Share a new key with your team, e.g. `{}`, and keep it out of the repository.",
+                Key::generate()
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    match encrypt(&key, &encryption::files(false)) {
+        Ok(unchanged) if unchanged > 0 => {
+            println!("{unchanged} file(s) were already encrypted.");
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Encrypts the plaintext `files`, returns how many were skipped because they were unchanged.
+fn encrypt(key: &Key, files: &[PathBuf]) -> Result<usize, String> {
+    let mut unchanged = 0;
+
+    for path in files {
+        let encrypted_path = encryption::encrypted_path(path);
+
+        let plaintext =
+            fs::read(path).map_err(|e| format!("Failed to read \"{}\": {e}", path.display()))?;
+
+        let encrypted = fs::read(&encrypted_path).ok();
+        if encrypted.and_then(|data| key.decrypt(&data)).as_ref() == Some(&plaintext) {
+            unchanged += 1;
+            continue;
+        }
+
+        fs::write(&encrypted_path, key.encrypt(&plaintext))
+            .map_err(|e| format!("Failed to write \"{}\": {e}", encrypted_path.display()))?;
+        println!("🔒 Encrypted \"{}\"", path.display());
+    }
+
+    Ok(unchanged)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::encrypt;
+    use crate::template::encryption::{encrypted_path, files_in, Key, TempData};
+
+    #[test]
+    fn skips_unchanged_files() {
+        let data = TempData::new("encrypt-inputs");
+        let key = Key::parse(&Key::generate()).unwrap();
+        let path = data.file("inputs", "01.txt");
+        fs::write(&path, "3   4\n").unwrap();
+        fs::write(data.file("puzzles", "01.md"), "## Day 1\n").unwrap();
+
+        assert_eq!(encrypt(&key, &files_in(&data.paths, false)), Ok(0));
+        let encrypted = fs::read(encrypted_path(&path)).unwrap();
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"3   4\n");
+
+        assert_eq!(encrypt(&key, &files_in(&data.paths, false)), Ok(2));
+        assert_eq!(fs::read(encrypted_path(&path)).unwrap(), encrypted);
+
+        fs::write(&path, "4   3\n").unwrap();
+        assert_eq!(encrypt(&key, &files_in(&data.paths, false)), Ok(1));
+        let encrypted = fs::read(encrypted_path(&path)).unwrap();
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"4   3\n");
+    }
+}
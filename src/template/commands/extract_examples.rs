@@ -0,0 +1,521 @@
+use std::{fs, io, path::Path, process};
+
+use crate::template::{config, encryption, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Day;
+
+/// A fenced code block of the puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CodeBlock {
+    /// The part whose description holds the block.
+    part: u8,
+    content: String,
+    /// Whether the paragraph right before the block mentions an example, e.g. "For example:".
+    follows_example: bool,
+}
+
+/// The example of a part and the answer highlighted for it in the description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Example {
+    /// 1-based index into all code blocks, as listed by `--list`.
+    block: usize,
+    content: String,
+    answer: Option<String>,
+}
+
+/// Writes the examples of the puzzle description of `day` to `data/examples` and fills the expected answers
+/// into the tests of its module. `block` and `part_two_block` pick other code blocks than the guessed ones,
+/// see `list`. Only tests that still expect `None` and empty example files are changed, unless `force` is set.
+pub fn handle(
+    day: Day,
+    block: Option<usize>,
+    part_two_block: Option<usize>,
+    list: bool,
+    force: bool,
+) {
+    let paths = &config::get().paths;
+    let puzzle_path = paths.day_file("puzzles", format!("{day}.md"));
+
+    let Ok(puzzle) = encryption::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "{} does not exist, download the puzzle first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    let blocks = code_blocks(&puzzle);
+
+    if list {
+        for (i, block) in blocks.iter().enumerate() {
+            println!(
+                "{ANSI_BOLD}Block {} (part {}){ANSI_RESET}",
+                i + 1,
+                block.part
+            );
+            println!("{}", block.content);
+        }
+        return;
+    }
+
+    let (part_one, part_two) = match find_examples(&puzzle, &blocks, block, part_two_block) {
+        Ok(examples) => examples,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let module_path = format!("src/bin/{day}.rs");
+    let mut module = fs::read_to_string(&module_path).ok();
+    if module.is_none() {
+        eprintln!("{module_path} does not exist, only writing the example files.");
+    }
+
+    // part two reads the example of part one, unless the puzzle gives it another one.
+    let part_two_has_own_example = part_two
+        .as_ref()
+        .is_some_and(|example| example.block != part_one.block);
+
+    if let Err(e) = write_example(
+        &paths.day_file("examples", format!("{day}.txt")),
+        &part_one,
+        force,
+    ) {
+        eprintln!("Failed to write example file: {e}");
+        process::exit(1);
+    }
+
+    if let Some(example) = part_two.as_ref().filter(|_| part_two_has_own_example) {
+        if let Err(e) = write_example(
+            &paths.day_file("examples", format!("{day}-2.txt")),
+            example,
+            force,
+        ) {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    for (part, example) in [(1, Some(&part_one)), (2, part_two.as_ref())] {
+        let (Some(example), Some(source)) = (example, module.as_mut()) else {
+            continue;
+        };
+
+        let Some(answer) = &example.answer else {
+            println!("No highlighted answer found for part {part}, fill in the test by hand.");
+            continue;
+        };
+
+        let reads_part_file = part == 2 && part_two_has_own_example;
+        match fill_test(source, part, answer, reads_part_file, force) {
+            Some(filled) => {
+                *source = filled;
+                println!("Expecting {answer} for part {part}");
+            }
+            None => println!(
+                "Kept the test of part {part}, it already expects an answer. Pass --force to overwrite it."
+            ),
+        }
+    }
+
+    if let Some(source) = module {
+        if let Err(e) = fs::write(&module_path, source) {
+            eprintln!("Failed to write module file: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!(
+        "{ANSI_ITALIC}Picked the wrong block? See --list and pass --block <N> or --part-two-block <N>.{ANSI_RESET}"
+    );
+}
+
+/// Writes the example unless the file already holds another one and `force` is not set.
+fn write_example(path: &Path, example: &Example, force: bool) -> io::Result<()> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    if !existing.trim().is_empty() && existing != example.content && !force {
+        println!(
+            "Kept \"{}\", it differs from block {}. Pass --force to overwrite it.",
+            path.display(),
+            example.block
+        );
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, &example.content)?;
+    println!("Wrote block {} to \"{}\"", example.block, path.display());
+    Ok(())
+}
+
+/// The examples of both parts, part two is `None` until its description is downloaded.
+fn find_examples(
+    puzzle: &str,
+    blocks: &[CodeBlock],
+    block: Option<usize>,
+    part_two_block: Option<usize>,
+) -> Result<(Example, Option<Example>), String> {
+    let pick = |index: usize| -> Result<(usize, &CodeBlock), String> {
+        match index.checked_sub(1).and_then(|i| blocks.get(i)) {
+            Some(block) => Ok((index, block)),
+            None => Err(format!(
+                "there is no block {index}, the puzzle has {} code blocks.",
+                blocks.len()
+            )),
+        }
+    };
+
+    // the first block that follows "For example", or the first block of the part.
+    let guess = |part: u8| {
+        blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| block.part == part)
+            .find(|(_, block)| block.follows_example)
+            .map(|(i, block)| (i + 1, block))
+    };
+
+    let (part_one_index, part_one_block) = match block {
+        Some(index) => pick(index)?,
+        None => guess(1)
+            .or_else(|| blocks.first().map(|block| (1, block)))
+            .ok_or("the puzzle has no code blocks.")?,
+    };
+
+    let (part_one_text, part_two_text) = split_parts(puzzle);
+
+    let part_one = Example {
+        block: part_one_index,
+        content: example_content(&part_one_block.content),
+        answer: highlighted_answers(part_one_text).pop(),
+    };
+
+    let Some(part_two_text) = part_two_text else {
+        return Ok((part_one, None));
+    };
+
+    let (part_two_index, part_two_block) = match part_two_block {
+        Some(index) => pick(index)?,
+        None => guess(2).unwrap_or((part_one_index, part_one_block)),
+    };
+
+    let part_two = Example {
+        block: part_two_index,
+        content: example_content(&part_two_block.content),
+        answer: highlighted_answers(part_two_text).pop(),
+    };
+
+    Ok((part_one, Some(part_two)))
+}
+
+/// The example files end with a line break, like the puzzle inputs.
+fn example_content(block: &str) -> String {
+    format!("{}\n", block.trim_end_matches('\n'))
+}
+
+/// Splits the description at the heading of part two.
+fn split_parts(puzzle: &str) -> (&str, Option<&str>) {
+    match puzzle.find("--- Part Two ---") {
+        Some(index) => (&puzzle[..index], Some(&puzzle[index..])),
+        None => (puzzle, None),
+    }
+}
+
+fn code_blocks(puzzle: &str) -> Vec<CodeBlock> {
+    let part_two_start = puzzle.find("--- Part Two ---").unwrap_or(puzzle.len());
+
+    let mut blocks = vec![];
+    let mut offset = 0;
+    let mut previous_end = 0;
+
+    while let Some(start) = puzzle[offset..].find("```") {
+        let start = offset + start;
+        let Some(content_start) = puzzle[start..].find('\n').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = puzzle[content_start..]
+            .find("```")
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+
+        let before = puzzle[previous_end..start].trim_end();
+        let paragraph = before.rsplit("\n\n").next().unwrap_or_default();
+
+        blocks.push(CodeBlock {
+            part: if start < part_two_start { 1 } else { 2 },
+            content: puzzle[content_start..end].to_string(),
+            follows_example: paragraph.to_lowercase().contains("example"),
+        });
+
+        offset = end + 3;
+        previous_end = offset;
+    }
+
+    blocks
+}
+
+/// Answers highlighted as emphasized code, either `` *`11`* `` or `` `*11*` ``, in order of appearance.
+fn highlighted_answers(text: &str) -> Vec<String> {
+    let mut answers = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['*', '`']) {
+        let open = &rest[start..];
+        let (prefix, suffix) = if open.starts_with("*`") {
+            ("*`", "`*")
+        } else if open.starts_with("`*") {
+            ("`*", "*`")
+        } else {
+            rest = &rest[start + 1..];
+            continue;
+        };
+
+        let inner = &open[prefix.len()..];
+        match inner.find(suffix) {
+            Some(end) if !inner[..end].contains(['`', '*', '\n']) && end > 0 => {
+                answers.push(inner[..end].to_string());
+                rest = &inner[end + suffix.len()..];
+            }
+            _ => rest = &rest[start + 1..],
+        }
+    }
+
+    answers
+}
+
+/// Replaces the expected value in the test of `part`, `None` if the test already expects an answer
+/// and `force` is not set. Numeric answers are filled in as is, others as strings.
+/// With `reads_part_file`, the test reads `{day}-{part}.txt`, see [`crate::template::read_file_part`].
+fn fill_test(
+    source: &str,
+    part: u8,
+    answer: &str,
+    reads_part_file: bool,
+    force: bool,
+) -> Option<String> {
+    let name = if part == 1 {
+        "test_part_one"
+    } else {
+        "test_part_two"
+    };
+
+    let expected = if answer.parse::<i64>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{answer:?}.to_string()")
+    };
+
+    let Some(start) = source.find(&format!("fn {name}(")) else {
+        return fill_case(source, name, part, &expected, reads_part_file, force);
+    };
+    let body_len = source[start + 1..]
+        .find("#[test]")
+        .map_or(source.len() - start, |end| end + 1);
+    let body = &source[start..start + body_len];
+
+    let assert_start = body.find("assert_eq!(result, ")?;
+    let assert_end = assert_start + body[assert_start..].find(");")? + 2;
+    let assertion = &body[assert_start..assert_end];
+
+    if assertion != "assert_eq!(result, None);" && !force {
+        return None;
+    }
+
+    let mut filled = body.replacen(
+        assertion,
+        &format!("assert_eq!(result, Some({expected}));"),
+        1,
+    );
+
+    if reads_part_file {
+        filled = filled.replacen(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {part})"),
+            1,
+        );
+    }
+
+    Some(format!(
+        "{}{filled}{}",
+        &source[..start],
+        &source[start + body_len..]
+    ))
+}
+
+/// Same as [`fill_test`] for a test generated by [`crate::example_tests`], which expects `None` for
+/// the empty case in `{name}: part_one => { "" => None, "a" => None }`.
+/// With `reads_part_file`, the case `"{part}"` is filled, or the empty case becomes that case.
+fn fill_case(
+    source: &str,
+    name: &str,
+    part: u8,
+    expected: &str,
+    reads_part_file: bool,
+    force: bool,
+) -> Option<String> {
+    let start = source.find(&format!("{name}: "))?;
+    let end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |end| start + end);
+    let line = &source[start..end];
+
+    let part_case = format!("\"{part}\" => ");
+    let (entry_start, case) = match line.find(&part_case) {
+        Some(entry_start) if reads_part_file => (entry_start, part_case.as_str()),
+        _ => (line.find("\"\" => ")?, "\"\" => "),
+    };
+
+    let value_start = entry_start + case.len();
+    let value_len = line[value_start..]
+        .find(", \"")
+        .or_else(|| line[value_start..].rfind(" }"))?;
+
+    if &line[value_start..value_start + value_len] != "None" && !force {
+        return None;
+    }
+
+    let case = if reads_part_file { &part_case } else { case };
+    Some(format!(
+        "{}{}{case}Some({expected}){}{}",
+        &source[..start],
+        &line[..entry_start],
+        &line[value_start + value_len..],
+        &source[end..]
+    ))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, fill_test, find_examples, highlighted_answers};
+
+    const PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+Some text with another block first:
+
+```
+not the example
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In this example, the answers are `*1*` and then *`11`*.
+
+## --- Part Two ---
+
+This time, use a different example:
+
+```
+1
+2
+```
+
+So, in this example, the similarity score is *`31`*.
+";
+
+    #[test]
+    fn finds_code_blocks() {
+        let blocks = code_blocks(PUZZLE);
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1].content, "3   4\n4   3\n");
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|b| (b.part, b.follows_example))
+                .collect::<Vec<_>>(),
+            vec![(1, false), (1, true), (2, true)]
+        );
+    }
+
+    #[test]
+    fn finds_highlighted_answers() {
+        assert_eq!(
+            highlighted_answers("a *`11`*, b `*4,6*`, not *this* or `that` or *`multi\nline`*"),
+            vec!["11", "4,6"]
+        );
+    }
+
+    #[test]
+    fn guesses_examples() {
+        let blocks = code_blocks(PUZZLE);
+        let (part_one, part_two) = find_examples(PUZZLE, &blocks, None, None).unwrap();
+        let part_two = part_two.unwrap();
+
+        assert_eq!(
+            (part_one.block, part_one.answer.as_deref()),
+            (2, Some("11"))
+        );
+        assert_eq!(
+            (part_two.block, part_two.answer.as_deref()),
+            (3, Some("31"))
+        );
+
+        let (part_one, _) = find_examples(PUZZLE, &blocks, Some(1), None).unwrap();
+        assert_eq!(part_one.content, "not the example\n");
+        assert!(find_examples(PUZZLE, &blocks, Some(4), None).is_err());
+    }
+
+    #[test]
+    fn fills_tests() {
+        let source = r#"
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+"#;
+
+        let filled = fill_test(source, 1, "11", false, false).unwrap();
+        assert!(filled.contains("assert_eq!(result, Some(11));"));
+        assert!(filled.contains("assert_eq!(result, None);"));
+
+        let filled = fill_test(&filled, 2, "a,b", true, false).unwrap();
+        assert!(filled.contains(r#"assert_eq!(result, Some("a,b".to_string()));"#));
+        assert!(filled.contains(r#"read_file_part("examples", DAY, 2)"#));
+
+        assert!(fill_test(&filled, 1, "12", false, false).is_none());
+        assert!(fill_test(&filled, 1, "12", false, true)
+            .unwrap()
+            .contains("Some(12)"));
+    }
+
+    #[test]
+    fn fills_example_tests() {
+        let source = r#"
+    advent_of_code::example_tests! {
+        test_part_one: part_one => { "" => None, "a" => None },
+        test_part_two: part_two => { "" => None, "a" => None },
+    }
+"#;
+
+        let filled = fill_test(source, 1, "11", false, false).unwrap();
+        assert!(filled.contains(r#"test_part_one: part_one => { "" => Some(11), "a" => None },"#));
+        assert!(filled.contains(r#"test_part_two: part_two => { "" => None, "a" => None },"#));
+
+        let filled = fill_test(&filled, 2, "a,b", true, false).unwrap();
+        assert!(filled.contains(
+            r#"test_part_two: part_two => { "2" => Some("a,b".to_string()), "a" => None },"#
+        ));
+
+        assert!(fill_test(&filled, 1, "12", false, false).is_none());
+        assert!(fill_test(&filled, 1, "12", false, true)
+            .unwrap()
+            .contains(r#"{ "" => Some(12), "a" => None }"#));
+    }
+}
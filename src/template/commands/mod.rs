@@ -1,5 +1,13 @@
 pub mod all;
+pub mod check_inputs;
+pub mod completions;
+pub mod decrypt_inputs;
 pub mod download;
+pub mod encrypt_inputs;
+pub mod extract_examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod test;
+pub mod watch_day;
@@ -1,16 +1,20 @@
 use std::process;
 
-use crate::template::aoc_cli;
+use crate::template::{aoc_cli, aoc_client};
 use crate::Day;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    // without a session cookie, fall back to aoc-cli which keeps its own.
+    if aoc_client::session().is_none() && aoc_cli::check().is_ok() {
+        if let Err(e) = aoc_cli::read(day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
+        return;
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = aoc_client::read(day) {
+        eprintln!("failed to read day {day}: {e}");
         process::exit(1);
     };
 }
@@ -1,26 +1,35 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
+use crate::template::{config, encryption};
 use crate::Day;
 
+/// The module of a new day. `PARSE_SKELETON` and `PARSE_INPUT` are filled from a [`Skeleton`],
+/// user templates in `templates/` may use the same placeholders as well as `DAY_NUMBER`, `ANSWER_TYPE`
+/// and `EXAMPLE_TESTS`.
 const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
-
-pub fn part_one(input: &str) -> Option<u32> {
-    None
+PARSE_SKELETON
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+PARSE_INPUT    None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
+PARSE_INPUT    None
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
+EXAMPLE_TESTS}
+"#;
+
+/// The tests of a day with a single example file.
+const EXAMPLE_TESTS: &str = r#"    #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, None);
@@ -31,20 +40,189 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, None);
     }
-}
 "#;
 
+/// Directory holding user-defined templates, `templates/<name>.rs` is selected with `--template <name>`.
+const USER_TEMPLATES: &str = "templates";
+
+/// The parse function of a built-in template and the line that calls it at the start of each part.
+struct Skeleton {
+    name: &'static str,
+    parse: &'static str,
+    /// Binds to an underscored name, so the new day builds without warnings until the parts use it.
+    call: &'static str,
+}
+
+const SKELETONS: &[Skeleton] = &[
+    Skeleton {
+        name: "default",
+        parse: "\n",
+        call: "",
+    },
+    Skeleton {
+        name: "grid",
+        parse: r#"
+fn parse(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+"#,
+        call: "    let _grid = parse(input);\n",
+    },
+    Skeleton {
+        name: "graph",
+        parse: r#"
+use std::collections::HashMap;
+
+fn parse(input: &str) -> HashMap<&str, Vec<&str>> {
+    let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+    for line in input.lines() {
+        let (from, to) = line.split_once('-').unwrap();
+        graph.entry(from).or_default().push(to);
+        graph.entry(to).or_default().push(from);
+    }
+    graph
+}
+
+"#,
+        call: "    let _graph = parse(input);\n",
+    },
+    Skeleton {
+        name: "blocks",
+        parse: r#"
+fn parse(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split("\n\n")
+        .map(|block| block.lines().collect())
+        .collect()
+}
+
+"#,
+        call: "    let _blocks = parse(input);\n",
+    },
+    Skeleton {
+        name: "regex-lines",
+        parse: r#"
+use regex::Regex;
+
+fn parse(input: &str) -> Vec<Vec<i64>> {
+    let re: Regex = Regex::new(r"-?\d+").unwrap();
+    input
+        .lines()
+        .map(|line| {
+            re.find_iter(line)
+                .map(|m| m.as_str().parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+"#,
+        call: "    let _lines = parse(input);\n",
+    },
+];
+
+/// Loads the template called `name`, a user template takes precedence over a built-in one.
+fn load_template(name: &str) -> Result<String, String> {
+    let user_path = Path::new(USER_TEMPLATES).join(format!("{name}.rs"));
+
+    if let Ok(template) = fs::read_to_string(&user_path) {
+        return Ok(render(&template, None));
+    }
+
+    match SKELETONS.iter().find(|skeleton| skeleton.name == name) {
+        Some(skeleton) => Ok(render(MODULE_TEMPLATE, Some(skeleton))),
+        None => Err(format!(
+            "unknown template `{name}`, expecting one of: {}",
+            template_names().join(", ")
+        )),
+    }
+}
+
+/// Names of the built-in templates followed by the ones found in `templates/`.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = SKELETONS.iter().map(|s| s.name.to_string()).collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATES) {
+        let mut user: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                name.strip_suffix(".rs").map(ToString::to_string)
+            })
+            .filter(|name| !names.contains(name))
+            .collect();
+        user.sort();
+        names.extend(user);
+    }
+
+    names
+}
+
+/// Fills the parse placeholders of `template`, user templates without a skeleton get the default one.
+fn render(template: &str, skeleton: Option<&Skeleton>) -> String {
+    let skeleton = skeleton.unwrap_or(&SKELETONS[0]);
+
+    template
+        .replace("PARSE_SKELETON\n", skeleton.parse)
+        .replace("PARSE_INPUT", skeleton.call)
+}
+
+/// Substitutes the day, answer and test placeholders of a rendered template.
+fn substitute(template: &str, day: Day, answer_type: &str, example_cases: &[String]) -> String {
+    template
+        .replace("EXAMPLE_TESTS", &example_tests(example_cases))
+        .replace("DAY_NUMBER", &day.into_inner().to_string())
+        .replace("ANSWER_TYPE", answer_type)
+}
+
+/// The tests of a new day, an [`crate::example_tests`] invocation that checks every example case if there are several.
+fn example_tests(example_cases: &[String]) -> String {
+    if example_cases.is_empty() {
+        return EXAMPLE_TESTS.to_string();
+    }
+
+    let cases: Vec<String> = std::iter::once("")
+        .chain(example_cases.iter().map(String::as_str))
+        .map(|case| format!("{case:?} => None"))
+        .collect();
+    let cases = cases.join(", ");
+
+    format!(
+        "    advent_of_code::example_tests! {{\n        test_part_one: part_one => {{ {cases} }},\n        test_part_two: part_two => {{ {cases} }},\n    }}\n"
+    )
+}
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    // the data folders are configurable, they may not exist yet.
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().write(true).create(true).truncate(false).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
+/// Creates the module, input and example files of `day`, plus an example file per case in `example_cases`,
+/// see [`crate::template::read_file_case`].
+pub fn handle(day: Day, template: &str, answer_type: &str, example_cases: &[String]) {
+    let template = match load_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let data_path = |folder: &str, name: String| {
+        let path = config::get().paths.day_file(folder, name);
+        path.to_string_lossy().into_owned()
+    };
+
+    let input_path = data_path("inputs", format!("{day}.txt"));
+    let example_path = data_path("examples", format!("{day}.txt"));
     let module_path = format!("src/bin/{day}.rs");
 
     let mut file = match safe_create_file(&module_path) {
@@ -55,11 +233,7 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(substitute(&template, day, answer_type, example_cases).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -69,13 +243,18 @@ pub fn handle(day: Day) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    // an empty input file would hide the encrypted one from `read_file`.
+    if encryption::encrypted_path(Path::new(&input_path)).exists() {
+        println!("Using encrypted input file \"{}.enc\"", &input_path);
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
@@ -89,6 +268,66 @@ pub fn handle(day: Day) {
         }
     }
 
+    for case in example_cases {
+        let case_path = data_path("examples", format!("{day}-{case}.txt"));
+        match create_file(&case_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &case_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {}` to run your solution.", day);
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, substitute, MODULE_TEMPLATE, SKELETONS};
+    use crate::day;
+
+    #[test]
+    fn renders_default_template() {
+        let module = substitute(&render(MODULE_TEMPLATE, None), day!(12), "u32", &[]);
+
+        assert!(module.starts_with("advent_of_code::solution!(12);\n\npub fn part_one(input: &str) -> Option<u32> {\n    None\n}"));
+        assert!(!module.contains("PARSE_"));
+        assert!(module.contains("    use super::*;\n\n    #[test]\n    fn test_part_one() {"));
+    }
+
+    #[test]
+    fn renders_example_tests() {
+        let cases = ["a".to_string(), "2".to_string()];
+        let module = substitute(&render(MODULE_TEMPLATE, None), day!(12), "u32", &cases);
+
+        assert!(module.contains(
+            "    advent_of_code::example_tests! {\n        test_part_one: part_one => { \"\" => None, \"a\" => None, \"2\" => None },\n"
+        ));
+        assert!(!module.contains("EXAMPLE_TESTS"));
+        assert!(!module.contains("#[test]"));
+    }
+
+    #[test]
+    fn renders_skeletons() {
+        for skeleton in SKELETONS {
+            let module = substitute(
+                &render(MODULE_TEMPLATE, Some(skeleton)),
+                day!(3),
+                "u64",
+                &[],
+            );
+
+            assert!(!module.contains("PARSE_"), "{}", skeleton.name);
+            assert!(!module.contains("ANSWER_TYPE"), "{}", skeleton.name);
+            assert!(module.contains("-> Option<u64>"), "{}", skeleton.name);
+        }
+
+        let grid = render(MODULE_TEMPLATE, Some(&SKELETONS[1]));
+        assert!(grid.contains("fn parse(input: &str) -> Vec<Vec<char>>"));
+        assert!(grid.contains("Option<ANSWER_TYPE> {\n    let _grid = parse(input);\n    None\n}"));
+    }
+}
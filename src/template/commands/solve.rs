@@ -1,14 +1,32 @@
 use std::process::{Command, Stdio};
+use std::time::Duration;
 
+use crate::template::{timeout_arg, InputSource, OutputFormat};
 use crate::Day;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Day,
+    release: bool,
+    time: bool,
+    submit_part: Option<u8>,
+    part: Option<u8>,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+    input: InputSource,
+) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    // the day binary only counts allocations if it is built the same way.
+    if cfg!(feature = "alloc_stats") {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("alloc_stats".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -16,10 +34,23 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
     if time {
         cmd_args.push("--time".to_string());
     }
 
+    // always forwarded, so they override the defaults in `aoc.toml` that the day binary reads too.
+    cmd_args.push("--format".to_string());
+    cmd_args.push(format.to_string());
+    cmd_args.push("--timeout".to_string());
+    cmd_args.push(timeout_arg(timeout));
+
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
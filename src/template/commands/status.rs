@@ -0,0 +1,99 @@
+use std::{fs, path::Path};
+
+use regex::Regex;
+
+use crate::all_days;
+use crate::template::{config, encryption, read_answer, readme_benchmarks, ANSI_BOLD, ANSI_RESET};
+
+/// Prints an overview of every day: which files exist, whether the parts are implemented,
+/// which answers are recorded and the last benchmark published to the README.
+///
+/// Nothing is executed, a part still returning the `None` of the scaffold is read from the module source.
+/// This keeps the command working while a day does not compile or loops forever.
+pub fn handle() {
+    let benchmarks = readme_benchmarks::read().unwrap_or_default();
+    let paths = &config::get().paths;
+
+    println!(
+        "{ANSI_BOLD}{:<4} {:<4} {:<7} {:<8} {:<8} {:<8} {:<8} Benchmark{ANSI_RESET}",
+        "Day", "Bin", "Input", "Example", "Part 1", "Part 2", "Answers"
+    );
+
+    for day in all_days() {
+        let source = fs::read_to_string(format!("src/bin/{day}.rs")).ok();
+        let [part_1, part_2] =
+            ["part_one", "part_two"].map(|name| part_status(source.as_deref(), name));
+
+        let answers: Vec<String> = [1, 2]
+            .into_iter()
+            .filter(|&part| read_answer(day, part).is_some())
+            .map(|part| part.to_string())
+            .collect();
+
+        let benchmark = benchmarks
+            .iter()
+            .find(|(benched, _, _)| *benched == day)
+            .map_or_else(
+                || "-".into(),
+                |(_, part_1, part_2)| format!("{part_1} · {part_2}"),
+            );
+
+        println!(
+            "{:<4} {:<4} {:<7} {:<8} {:<8} {:<8} {:<8} {benchmark}",
+            day.to_string(),
+            if source.is_some() { "✔" } else { "✖" },
+            file_status(&paths.day_file("inputs", format!("{day}.txt"))),
+            file_status(&paths.day_file("examples", format!("{day}.txt"))),
+            part_1,
+            part_2,
+            if answers.is_empty() {
+                "-".into()
+            } else {
+                answers.join(",")
+            },
+        );
+    }
+}
+
+fn file_status(path: &Path) -> &'static str {
+    match fs::metadata(path) {
+        Ok(meta) if meta.len() > 0 => "✔",
+        Ok(_) => "empty",
+        Err(_) if encryption::encrypted_path(path).exists() => "enc",
+        Err(_) => "✖",
+    }
+}
+
+/// `None` if the part still returns the `None` of the scaffold, `-` if the module or part is missing.
+fn part_status(source: Option<&str>, name: &str) -> &'static str {
+    let Some(source) = source else {
+        return "-";
+    };
+
+    if !source.contains(&format!("fn {name}")) {
+        return "-";
+    }
+
+    let stub = Regex::new(&format!(r"fn {name}\b[^{{]*\{{\s*None\s*\}}")).unwrap();
+    if stub.is_match(source) {
+        "None"
+    } else {
+        "✔"
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::part_status;
+
+    #[test]
+    fn reads_part_status_from_source() {
+        let source = "pub fn part_one(input: &str) -> Option<u32> {\n    None\n}\n\n\
+                      pub fn part_two(input: &str) -> Option<u32> {\n    Some(input.len() as u32)\n}\n";
+
+        assert_eq!(part_status(Some(source), "part_one"), "None");
+        assert_eq!(part_status(Some(source), "part_two"), "✔");
+        assert_eq!(part_status(Some("fn main() {}"), "part_one"), "-");
+        assert_eq!(part_status(None, "part_one"), "-");
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    fs,
+    io::{stdout, Write},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+use crate::{Day, DaySet};
+
+/// The outcome of the example test of one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestResult {
+    Pass,
+    Fail,
+    /// The test passes, but only because it asserts that the part returns `None`.
+    ExpectsNone,
+    Missing,
+}
+
+impl TestResult {
+    fn label(self) -> &'static str {
+        match self {
+            TestResult::Pass => "pass",
+            TestResult::Fail => "fail",
+            TestResult::ExpectsNone => "None",
+            TestResult::Missing => "-",
+        }
+    }
+}
+
+/// Runs the example tests of every selected day and prints one row per day.
+pub fn handle(days: DaySet) {
+    let mut has_failed = false;
+
+    println!(
+        "{ANSI_BOLD}{:<5} {:<7} {:<7} Status{ANSI_RESET}",
+        "Day", "Part 1", "Part 2"
+    );
+
+    for day in days.iter() {
+        print!("{:<5} ", day.to_string());
+        let _ = stdout().flush();
+
+        let Ok(source) = fs::read_to_string(format!("src/bin/{day}.rs")) else {
+            println!("{:<7} {:<7} not scaffolded", "-", "-");
+            continue;
+        };
+
+        let Some(output) = run_tests(day) else {
+            has_failed = true;
+            println!("{:<7} {:<7} ✖ does not compile", "-", "-");
+            continue;
+        };
+
+        let results = [1, 2].map(|part| {
+            let name = test_name(part);
+            match output.iter().find(|(test, _)| test.ends_with(name)) {
+                None => TestResult::Missing,
+                Some((_, false)) => TestResult::Fail,
+                Some((_, true)) if expects_none(&source, name) => TestResult::ExpectsNone,
+                Some((_, true)) => TestResult::Pass,
+            }
+        });
+
+        // a failing test may also assert `None`, e.g. for a part that was never solved.
+        let asserts_none = [1, 2]
+            .iter()
+            .any(|&part| expects_none(&source, test_name(part)));
+
+        let status = match (results.contains(&TestResult::Fail), asserts_none) {
+            (true, true) => "✖ fail, expects None",
+            (true, false) => "✖ fail",
+            (false, true) => "⚠ expects None",
+            (false, false) => "✔ pass",
+        };
+        has_failed |= results.contains(&TestResult::Fail);
+
+        println!(
+            "{:<7} {:<7} {status}",
+            results[0].label(),
+            results[1].label()
+        );
+    }
+
+    if has_failed {
+        process::exit(1);
+    }
+}
+
+fn test_name(part: u8) -> &'static str {
+    match part {
+        1 => "test_part_one",
+        _ => "test_part_two",
+    }
+}
+
+/// Runs the tests of a day binary and returns the name and outcome of each test,
+/// or `None` if the binary does not compile.
+fn run_tests(day: Day) -> Option<Vec<(String, bool)>> {
+    let output = Command::new("cargo")
+        .args(["test", "--bin", &day.to_string()])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let results = parse_test_output(&stdout);
+
+    // a failing test also exits non-zero, but only a build error prints no results.
+    if results.is_empty() && !output.status.success() {
+        return None;
+    }
+
+    Some(results)
+}
+
+/// Parses the `test <name> ... ok|FAILED` lines printed by the test harness.
+fn parse_test_output(stdout: &str) -> Vec<(String, bool)> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test "))
+        .filter_map(|line| line.rsplit_once(" ... "))
+        .filter_map(|(name, outcome)| match outcome.trim() {
+            "ok" => Some((name.to_string(), true)),
+            "FAILED" => Some((name.to_string(), false)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether the body of test `name` in `source` asserts a `None` result, as scaffolded tests do until a part is solved.
+/// Tests generated by [`crate::example_tests`] expect `None` if any of their cases does.
+fn expects_none(source: &str, name: &str) -> bool {
+    let Some(start) = source.find(&format!("fn {name}(")) else {
+        return source
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with(&format!("{name}: ")))
+            .is_some_and(|line| line.contains("=> None"));
+    };
+
+    let body = &source[start..];
+    let end = body[1..].find("#[test]").map_or(body.len(), |end| end + 1);
+
+    body[..end].contains("assert_eq!(result, None)")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{expects_none, parse_test_output};
+
+    #[test]
+    fn parses_test_output() {
+        let stdout = "\nrunning 2 tests\ntest tests::test_part_one ... ok\ntest tests::test_part_two ... FAILED\n\ntest result: FAILED. 1 passed; 1 failed";
+
+        assert_eq!(
+            parse_test_output(stdout),
+            vec![
+                ("tests::test_part_one".to_string(), true),
+                ("tests::test_part_two".to_string(), false)
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_none_assertions() {
+        let source = r#"
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+"#;
+        assert!(!expects_none(source, "test_part_one"));
+        assert!(expects_none(source, "test_part_two"));
+        assert!(!expects_none(source, "test_part_three"));
+    }
+
+    #[test]
+    fn detects_none_example_cases() {
+        let source = r#"
+    advent_of_code::example_tests! {
+        test_part_one: part_one => { "" => Some(11), "a" => Some(3) },
+        test_part_two: part_two => { "" => Some(31), "a" => None },
+    }
+"#;
+        assert!(!expects_none(source, "test_part_one"));
+        assert!(expects_none(source, "test_part_two"));
+        assert!(!expects_none(source, "test_part_three"));
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fs,
+    io::{stdout, Write},
+    path::PathBuf,
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    commands::solve, config, encryption, file_cases, InputSource, ANSI_BOLD, ANSI_ITALIC,
+    ANSI_RESET,
+};
+use crate::Day;
+
+/// Clears the terminal and moves the cursor to the top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Polls the solution, examples and input of `day` every `interval` and re-runs its tests and/or
+/// its solution whenever one of them changes. Only uses modification times, no file system events.
+pub fn handle(
+    day: Day,
+    run_tests: bool,
+    run_solve: bool,
+    release: bool,
+    input: InputSource,
+    interval: Duration,
+) {
+    let module_path = PathBuf::from(format!("src/bin/{day}.rs"));
+
+    if !module_path.exists() {
+        eprintln!("{} does not exist, scaffold the day first.", module_path.display());
+        process::exit(1);
+    }
+
+    let mut last_seen = modified_times(&watched_paths(day));
+
+    loop {
+        print!("{CLEAR_SCREEN}");
+        println!("{ANSI_BOLD}Watching Day {day}{ANSI_RESET} {ANSI_ITALIC}(Ctrl-C to stop){ANSI_RESET}");
+        println!();
+
+        let tests_passed = run_tests.then(|| {
+            let status = Command::new("cargo")
+                .args(["test", "--quiet", "--bin", &day.to_string()])
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status();
+            matches!(status, Ok(status) if status.success())
+        });
+
+        if run_solve {
+            println!();
+            solve::handle(
+                day,
+                release,
+                false,
+                None,
+                None,
+                config::get().defaults.format,
+                config::get().defaults.timeout,
+                input.clone(),
+            );
+        }
+
+        println!();
+        match tests_passed {
+            Some(true) => println!("{ANSI_BOLD}✔ tests passed{ANSI_RESET}"),
+            Some(false) => println!("{ANSI_BOLD}✖ tests failed{ANSI_RESET}"),
+            None => {}
+        }
+        println!("{ANSI_ITALIC}Waiting for changes...{ANSI_RESET}");
+        let _ = stdout().flush();
+
+        // editors often save in several steps, wait until the files settle before re-running.
+        loop {
+            thread::sleep(interval);
+            let current = modified_times(&watched_paths(day));
+            if current != last_seen {
+                thread::sleep(interval);
+                last_seen = modified_times(&watched_paths(day));
+                break;
+            }
+        }
+    }
+}
+
+/// The solution, the example files including every case and the input, plain or encrypted, of `day`.
+/// Listed again on every poll, so a case added while watching is picked up.
+fn watched_paths(day: Day) -> Vec<PathBuf> {
+    let data = &config::get().paths;
+    let input_path = data.day_file("inputs", format!("{day}.txt"));
+
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{day}.rs")),
+        data.day_file("examples", format!("{day}.txt")),
+        encryption::encrypted_path(&input_path),
+        input_path,
+    ];
+    paths.extend(
+        file_cases("examples", day)
+            .iter()
+            .map(|case| data.day_file("examples", format!("{day}-{case}.txt"))),
+    );
+    paths
+}
+
+/// The modification time of every path, `None` for files that do not exist (yet).
+fn modified_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
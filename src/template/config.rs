@@ -0,0 +1,346 @@
+/// Module that reads the project configuration from `aoc.toml` in the project root.
+/// Every setting is optional, a missing file is the same as an empty one.
+/// `AOC_YEAR`, `AOC_DATA_DIR`, `AOC_README`, `AOC_SESSION`, `AOC_BASE_URL` and `AOC_INPUT_KEY` override the file,
+/// `AOC_CONFIG` reads another file.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::template::{parse_duration, OutputFormat};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(toml::de::Error),
+    IO(io::Error),
+    Invalid(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::Parser(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "failed to parse config: {e}"),
+            Error::IO(e) => write!(f, "failed to read config: {e}"),
+            Error::Invalid(e) => write!(f, "invalid setting: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The puzzle year passed to aoc-cli, the current event when not set.
+    pub year: Option<u16>,
+    pub paths: Paths,
+    pub defaults: Defaults,
+    pub bench: Bench,
+    pub client: ClientSettings,
+    pub encryption: EncryptionSettings,
+}
+
+/// Where the template reads and writes its files.
+/// The solutions always live in `src/bin`, cargo only discovers binaries there.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Paths {
+    /// The folder holding `inputs`, `examples`, `puzzles`, `answers` and `baselines`.
+    pub data: PathBuf,
+    /// Overrides `{data}/inputs`, e.g. to keep the inputs out of the repository.
+    pub inputs: Option<PathBuf>,
+    pub examples: Option<PathBuf>,
+    pub puzzles: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    pub baselines: Option<PathBuf>,
+    /// The README whose benchmark table `cargo time` updates.
+    pub readme: PathBuf,
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Self {
+            data: PathBuf::from("data"),
+            inputs: None,
+            examples: None,
+            puzzles: None,
+            answers: None,
+            baselines: None,
+            readme: PathBuf::from("README.md"),
+        }
+    }
+}
+
+impl Paths {
+    /// The folder of a kind of file, e.g. `data/inputs` for `"inputs"`.
+    #[must_use]
+    pub fn dir(&self, folder: &str) -> PathBuf {
+        let configured = match folder {
+            "inputs" => self.inputs.as_ref(),
+            "examples" => self.examples.as_ref(),
+            "puzzles" => self.puzzles.as_ref(),
+            "answers" => self.answers.as_ref(),
+            "baselines" => self.baselines.as_ref(),
+            _ => None,
+        };
+
+        configured.map_or_else(|| self.data.join(folder), Clone::clone)
+    }
+
+    /// The file of a day in a folder, e.g. `data/inputs/01.txt`.
+    #[must_use]
+    pub fn day_file(&self, folder: &str, name: impl AsRef<Path>) -> PathBuf {
+        self.dir(folder).join(name)
+    }
+}
+
+/// Values of the command line flags that are not passed.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Defaults {
+    pub format: OutputFormat,
+    #[serde(deserialize_with = "deserialize_some_duration")]
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+    pub template: String,
+    pub answer_type: String,
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub interval: Duration,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::Text,
+            timeout: None,
+            jobs: 1,
+            template: "default".into(),
+            answer_type: "u32".into(),
+            interval: Duration::from_millis(500),
+        }
+    }
+}
+
+/// How parts are benchmarked by `--time`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Bench {
+    /// Roughly how long to sample each part, the number of samples follows from its first run.
+    #[serde(deserialize_with = "deserialize_duration")]
+    pub target: Duration,
+    pub min_samples: u32,
+    pub max_samples: u32,
+    /// Slowdown in percent reported as a regression against a baseline.
+    pub threshold: f64,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            target: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+            threshold: 10_f64,
+        }
+    }
+}
+
+/// How the template talks to the Advent of Code website, see [`crate::template::aoc_client`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientSettings {
+    pub base_url: String,
+    /// Sent with every request, the site asks automated tools to say how to contact their author,
+    /// e.g. `advent_of_code (+https://github.com/{user}/{repository})`. Required to talk to the site.
+    pub user_agent: Option<String>,
+    /// The `session` cookie of a logged in browser. This file is usually committed,
+    /// prefer `AOC_SESSION` or `~/.adventofcode.session`.
+    pub session: Option<String>,
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        Self {
+            base_url: "https://adventofcode.com".into(),
+            user_agent: None,
+            session: None,
+        }
+    }
+}
+
+/// Where the key of the encrypted inputs is read from, see [`crate::template::encryption`].
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EncryptionSettings {
+    /// 64 hex digits. This file is usually committed, prefer `AOC_INPUT_KEY` or `key_file`.
+    pub key: Option<String>,
+    /// A file holding the key, e.g. an ignored `.aoc-key` in the project root.
+    pub key_file: Option<PathBuf>,
+}
+
+fn deserialize_duration<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    parse_duration(&s).map_err(de::Error::custom)
+}
+
+fn deserialize_some_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Duration>, D::Error> {
+    deserialize_duration(deserializer).map(Some)
+}
+
+impl Config {
+    pub fn from_toml(content: &str) -> Result<Self, Error> {
+        let config: Config = toml::from_str(content)?;
+
+        if config.bench.min_samples == 0 || config.bench.min_samples > config.bench.max_samples {
+            return Err(Error::Invalid(
+                "bench.min_samples must be at least 1 and at most bench.max_samples".into(),
+            ));
+        }
+
+        Ok(config)
+    }
+
+    /// Reads `aoc.toml`, or the file in `AOC_CONFIG`, and applies the environment overrides.
+    pub fn load() -> Result<Self, Error> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| CONFIG_FILE.into());
+
+        let mut config = match fs::read_to_string(&path) {
+            Ok(content) => Self::from_toml(&content)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        config.apply_env(|name| env::var(name).ok())?;
+        Ok(config)
+    }
+
+    /// Overrides the settings that have an environment variable, `lookup` returns the value of a variable.
+    pub fn apply_env(&mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<(), Error> {
+        if let Some(year) = lookup("AOC_YEAR") {
+            self.year = Some(
+                year.parse()
+                    .map_err(|_| Error::Invalid(format!("AOC_YEAR=\"{year}\" is not a year")))?,
+            );
+        }
+        if let Some(data) = lookup("AOC_DATA_DIR") {
+            self.paths.data = PathBuf::from(data);
+        }
+        if let Some(readme) = lookup("AOC_README") {
+            self.paths.readme = PathBuf::from(readme);
+        }
+        if let Some(session) = lookup("AOC_SESSION") {
+            self.client.session = Some(session);
+        }
+        if let Some(base_url) = lookup("AOC_BASE_URL") {
+            self.client.base_url = base_url;
+        }
+        if let Some(key) = lookup("AOC_INPUT_KEY") {
+            self.encryption.key = Some(key);
+        }
+        Ok(())
+    }
+}
+
+/// The configuration of the project, loaded on first use.
+///
+/// # Panics
+///
+/// If the configuration file or an environment override is invalid.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| Config::load().unwrap_or_else(|e| panic!("{CONFIG_FILE}: {e}")))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::Config;
+    use crate::template::OutputFormat;
+
+    #[test]
+    fn defaults_without_settings() {
+        let config = Config::from_toml("").unwrap();
+
+        assert_eq!(config, Config::default());
+        assert_eq!(config.paths.dir("inputs"), PathBuf::from("data/inputs"));
+        assert_eq!(config.bench.target, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn reads_settings() {
+        let config = Config::from_toml(
+            r#"
+year = 2024
+
+[paths]
+data = "puzzles"
+inputs = "/secret/inputs"
+readme = "docs/README.md"
+
+[defaults]
+format = "json"
+timeout = "10s"
+
+[bench]
+target = "250ms"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.paths.dir("inputs"), PathBuf::from("/secret/inputs"));
+        assert_eq!(
+            config.paths.dir("examples"),
+            PathBuf::from("puzzles/examples")
+        );
+        assert_eq!(config.paths.readme, PathBuf::from("docs/README.md"));
+        assert_eq!(config.defaults.format, OutputFormat::Json);
+        assert_eq!(config.defaults.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(config.defaults.jobs, 1);
+        assert_eq!(config.bench.target, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(Config::from_toml("yaer = 2024").is_err());
+        assert!(Config::from_toml("[defaults]\ntimeout = \"soon\"").is_err());
+        assert!(Config::from_toml("[bench]\nmin_samples = 0").is_err());
+    }
+
+    #[test]
+    fn env_overrides_file() {
+        let mut config = Config::from_toml("year = 2023").unwrap();
+        config
+            .apply_env(|name| match name {
+                "AOC_YEAR" => Some("2024".into()),
+                "AOC_DATA_DIR" => Some("other".into()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.paths.dir("answers"), PathBuf::from("other/answers"));
+        assert!(config.apply_env(|_| Some("twenty".into())).is_err());
+    }
+}
@@ -0,0 +1,401 @@
+/// Module that encrypts the puzzle inputs and descriptions, so they can be committed without publishing them.
+/// `{file}.enc` holds a random nonce followed by the ChaCha20-Poly1305 ciphertext of `{file}`.
+/// The key is 32 bytes written as 64 hex digits, read from `AOC_INPUT_KEY` or the `[encryption]` settings.
+use std::{
+    ffi::OsString,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::template::config::{self, EncryptionSettings, Paths};
+
+/// The folders whose files are encrypted.
+pub const FOLDERS: [&str; 2] = ["inputs", "puzzles"];
+
+pub const EXTENSION: &str = "enc";
+
+/// Marks the format of an encrypted file, followed by the nonce.
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    MissingKey,
+    InvalidKey,
+    /// The file was encrypted with another key or is damaged.
+    Corrupted(PathBuf),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingKey => write!(
+                f,
+                "no key for the encrypted inputs, set AOC_INPUT_KEY or encryption.key_file in aoc.toml."
+            ),
+            Error::InvalidKey => write!(f, "the key of the encrypted inputs must be 64 hex digits."),
+            Error::Corrupted(path) => write!(
+                f,
+                "could not decrypt {}, it was encrypted with another key or is damaged.",
+                path.display()
+            ),
+            Error::IO(e) => write!(f, "failed to access encrypted file: {e}"),
+        }
+    }
+}
+
+pub struct Key(ChaCha20Poly1305);
+
+impl Key {
+    pub fn parse(hex: &str) -> Result<Self, Error> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(Error::InvalidKey);
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| Error::InvalidKey)?;
+
+        ChaCha20Poly1305::new_from_slice(&bytes)
+            .map(Key)
+            .map_err(|_| Error::InvalidKey)
+    }
+
+    /// The key in `AOC_INPUT_KEY`, `encryption.key` or `encryption.key_file`.
+    pub fn from_config() -> Result<Self, Error> {
+        Self::from_settings(&config::get().encryption)
+    }
+
+    pub fn from_settings(settings: &EncryptionSettings) -> Result<Self, Error> {
+        if let Some(key) = &settings.key {
+            return Self::parse(key);
+        }
+
+        match &settings.key_file {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(key) => Self::parse(&key),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Err(Error::MissingKey),
+                Err(e) => Err(e.into()),
+            },
+            None => Err(Error::MissingKey),
+        }
+    }
+
+    /// A new random key, as 64 hex digits.
+    #[must_use]
+    pub fn generate() -> String {
+        ChaCha20Poly1305::generate_key(&mut OsRng)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .0
+            .encrypt(&nonce, plaintext)
+            .expect("the input fits into a single message");
+
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// The plaintext of `data`, `None` if it was not encrypted with this key.
+    #[must_use]
+    pub fn decrypt(&self, data: &[u8]) -> Option<Vec<u8>> {
+        let data = data.strip_prefix(MAGIC)?;
+        if data.len() < NONCE_LEN {
+            return None;
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        self.0.decrypt(Nonce::from_slice(nonce), ciphertext).ok()
+    }
+
+    pub fn decrypt_file(&self, path: &Path) -> Result<String, Error> {
+        let plaintext = self
+            .decrypt(&fs::read(path)?)
+            .ok_or_else(|| Error::Corrupted(path.to_path_buf()))?;
+        String::from_utf8(plaintext).map_err(|_| Error::Corrupted(path.to_path_buf()))
+    }
+}
+
+/// The encrypted counterpart of a file, e.g. `data/inputs/01.txt.enc` for `data/inputs/01.txt`.
+#[must_use]
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+/// The plaintext counterpart of an encrypted file, `None` for other files.
+#[must_use]
+pub fn plaintext_path(path: &Path) -> Option<PathBuf> {
+    (path.extension()? == EXTENSION).then(|| path.with_extension(""))
+}
+
+/// Reads `path`, or decrypts `{path}.enc` if only the encrypted file exists.
+/// Warns if both exist and differ, e.g. after pulling a newer encrypted input.
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    read_with_settings(path, &config::get().encryption)
+}
+
+fn read_with_settings(path: &Path, settings: &EncryptionSettings) -> io::Result<String> {
+    let encrypted = encrypted_path(path);
+
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if !encrypted.exists() {
+                return Err(e);
+            }
+
+            Key::from_settings(settings)
+                .and_then(|key| key.decrypt_file(&encrypted))
+                .map_err(|e| io::Error::other(e.to_string()))
+        }
+        Ok(plaintext) => {
+            if differs_from_encrypted(&plaintext, &encrypted, settings) {
+                eprintln!(
+                    "Warning: \"{}\" differs from \"{}\", run `cargo encrypt-inputs` or `cargo decrypt-inputs --force`.",
+                    path.display(),
+                    encrypted.display()
+                );
+            }
+            Ok(plaintext)
+        }
+        result => result,
+    }
+}
+
+/// Whether `encrypted` holds another text than `plaintext`, `false` without the file or the key.
+fn differs_from_encrypted(
+    plaintext: &str,
+    encrypted: &Path,
+    settings: &EncryptionSettings,
+) -> bool {
+    encrypted.exists()
+        && Key::from_settings(settings)
+            .and_then(|key| key.decrypt_file(encrypted))
+            .is_ok_and(|decrypted| decrypted != plaintext)
+}
+
+/// Whether `path` is a plaintext file of an encrypted folder, e.g. `data/inputs/01.txt` but not
+/// `data/inputs/01.txt.enc` or `data/inputs/.keep`.
+#[must_use]
+pub fn is_plaintext(path: &Path) -> bool {
+    !is_hidden(path) && plaintext_path(path).is_none()
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+/// The files of the encrypted folders, `encrypted` selects the `.enc` files or the plaintext ones.
+/// Hidden files such as `.keep` are skipped.
+#[must_use]
+pub fn files(encrypted: bool) -> Vec<PathBuf> {
+    files_in(&config::get().paths, encrypted)
+}
+
+#[must_use]
+pub fn files_in(paths: &Paths, encrypted: bool) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = FOLDERS
+        .iter()
+        .filter_map(|folder| fs::read_dir(paths.dir(folder)).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && !is_hidden(path))
+        .filter(|path| plaintext_path(path).is_some() == encrypted)
+        .collect();
+
+    files.sort();
+    files
+}
+
+/// An empty data folder in the temporary directory of the system, removed on drop.
+#[cfg(feature = "test_lib")]
+pub(crate) struct TempData {
+    pub paths: Paths,
+}
+
+#[cfg(feature = "test_lib")]
+impl TempData {
+    pub fn new(name: &str) -> Self {
+        let data = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&data);
+
+        for folder in FOLDERS {
+            fs::create_dir_all(data.join(folder)).unwrap();
+        }
+
+        Self {
+            paths: Paths {
+                data,
+                ..Paths::default()
+            },
+        }
+    }
+
+    pub fn file(&self, folder: &str, name: &str) -> PathBuf {
+        self.paths.day_file(folder, name)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+impl Drop for TempData {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.paths.data);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::{
+        differs_from_encrypted, encrypted_path, files_in, is_plaintext, plaintext_path,
+        read_with_settings, Key, TempData,
+    };
+    use crate::template::config::EncryptionSettings;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn settings(key: Option<&str>) -> EncryptionSettings {
+        EncryptionSettings {
+            key: key.map(ToString::to_string),
+            key_file: None,
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let key = Key::parse(KEY).unwrap();
+        let encrypted = key.encrypt(b"3   4\n4   3\n");
+
+        assert_ne!(encrypted, key.encrypt(b"3   4\n4   3\n"));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"3   4\n4   3\n");
+
+        let other = Key::parse(&Key::generate()).unwrap();
+        assert_eq!(other.decrypt(&encrypted), None);
+        assert_eq!(key.decrypt(b"3   4\n"), None);
+    }
+
+    #[test]
+    fn parses_keys() {
+        assert!(Key::parse(&format!("{KEY}\n")).is_ok());
+        assert!(Key::parse(&KEY[2..]).is_err());
+        assert!(Key::parse(&KEY.replace('0', "g")).is_err());
+        assert_eq!(Key::generate().len(), 64);
+    }
+
+    #[test]
+    fn maps_paths() {
+        let path = Path::new("data/inputs/01.txt");
+
+        assert_eq!(
+            encrypted_path(path),
+            PathBuf::from("data/inputs/01.txt.enc")
+        );
+        assert_eq!(
+            plaintext_path(&encrypted_path(path)),
+            Some(path.to_path_buf())
+        );
+        assert_eq!(plaintext_path(path), None);
+        assert!(is_plaintext(path));
+        assert!(!is_plaintext(&encrypted_path(path)));
+        assert!(!is_plaintext(Path::new("data/inputs/.keep")));
+    }
+
+    #[test]
+    fn reads_encrypted_files() {
+        let data = TempData::new("read-encrypted");
+        let path = data.file("inputs", "01.txt");
+        let key = Key::parse(KEY).unwrap();
+        fs::write(encrypted_path(&path), key.encrypt(b"3   4\n")).unwrap();
+
+        assert_eq!(
+            read_with_settings(&path, &settings(Some(KEY))).unwrap(),
+            "3   4\n"
+        );
+
+        let missing = read_with_settings(&path, &settings(None)).unwrap_err();
+        assert!(missing.to_string().starts_with("no key"));
+
+        let wrong = read_with_settings(&path, &settings(Some(&Key::generate()))).unwrap_err();
+        assert!(wrong.to_string().starts_with("could not decrypt"));
+
+        let unknown = read_with_settings(&data.file("inputs", "02.txt"), &settings(Some(KEY)));
+        assert_eq!(unknown.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn prefers_plaintext_files() {
+        let data = TempData::new("read-plaintext");
+        let path = data.file("inputs", "01.txt");
+        let encrypted = encrypted_path(&path);
+        let key = Key::parse(KEY).unwrap();
+        fs::write(&path, "3   4\n").unwrap();
+        fs::write(&encrypted, key.encrypt(b"3   4\n")).unwrap();
+
+        assert_eq!(
+            read_with_settings(&path, &settings(Some(KEY))).unwrap(),
+            "3   4\n"
+        );
+        assert!(!differs_from_encrypted(
+            "3   4\n",
+            &encrypted,
+            &settings(Some(KEY))
+        ));
+        assert!(differs_from_encrypted(
+            "4   3\n",
+            &encrypted,
+            &settings(Some(KEY))
+        ));
+        assert!(!differs_from_encrypted(
+            "4   3\n",
+            &encrypted,
+            &settings(None)
+        ));
+    }
+
+    #[test]
+    fn lists_files() {
+        let data = TempData::new("list-files");
+        for name in ["01.txt", "01.txt.enc", ".keep"] {
+            fs::write(data.file("inputs", name), "").unwrap();
+        }
+        fs::write(data.file("puzzles", "01.md"), "").unwrap();
+
+        assert_eq!(
+            files_in(&data.paths, false),
+            [data.file("inputs", "01.txt"), data.file("puzzles", "01.md")]
+        );
+        assert_eq!(
+            files_in(&data.paths, true),
+            [data.file("inputs", "01.txt.enc")]
+        );
+    }
+}
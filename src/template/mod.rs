@@ -1,37 +1,420 @@
 use crate::Day;
-use std::{env, fs};
+use serde::Deserialize;
+use std::io::{self, Read};
+use std::{env, fmt::Display, fs, path::PathBuf, str::FromStr, time::Duration};
 
+pub mod alloc;
+pub mod aoc_client;
 pub mod aoc_cli;
+pub mod baseline;
 pub mod commands;
+pub mod config;
+pub mod encryption;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod solution;
+pub mod submissions;
+pub mod usage;
+
+pub use solution::Solution;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human readable console output.
+    #[default]
+    Text,
+    /// One JSON record per line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown output format `{s}`, expecting `text` or `json`")),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => f.write_str("text"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// Parses a duration such as `10s`, `500ms` or `2m`. A bare number is read as seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`, expecting e.g. `10s` or `500ms`"))?;
+
+    let secs = match unit {
+        "ms" => value / 1000_f64,
+        "" | "s" => value,
+        "m" => value * 60_f64,
+        _ => return Err(format!("unknown unit `{unit}` in duration `{s}`, expecting `ms`, `s` or `m`")),
+    };
+
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("invalid duration `{s}`: {e}"))
+}
+
+/// Parses a `--timeout`, `none` or a zero duration turn off a timeout set in `aoc.toml`.
+pub fn parse_timeout(s: &str) -> Result<Option<Duration>, String> {
+    if s.trim() == "none" {
+        return Ok(None);
+    }
+
+    parse_duration(s).map(|timeout| Some(timeout).filter(|timeout| !timeout.is_zero()))
+}
+
+/// The `--timeout` value of a timeout resolved by [`parse_timeout`].
+#[must_use]
+pub fn timeout_arg(timeout: Option<Duration>) -> String {
+    timeout.map_or_else(|| "none".to_string(), |timeout| format!("{}s", timeout.as_secs_f64()))
+}
 
 /// Helper function that reads a text file to a string.
+/// Without the file, its encrypted `.enc` counterpart is decrypted, see [`encryption`].
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+    let f = try_read_file(folder, day);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads the text file of a part, e.g. `data/examples/{day}-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    read_file_case(folder, day, &part.to_string())
+}
+
+/// Helper function that reads one of several text files of a day, `data/{folder}/{day}-{case}.txt`.
+/// The empty case reads `data/{folder}/{day}.txt`, see [`read_file`].
+#[must_use]
+pub fn read_file_case(folder: &str, day: Day, case: &str) -> String {
+    let name = case_file_name(day, case);
+    try_read_file_named(folder, &name).unwrap_or_else(|e| {
+        let path = config::get().paths.day_file(folder, &name);
+        panic!("could not open file {}: {e}", path.display())
+    })
+}
+
+/// The cases of a day in `data/{folder}`, e.g. `["1", "2", "a"]` for `{day}-1.txt`, `{day}-2.txt` and `{day}-a.txt`.
+/// The file without a case is not included.
+#[must_use]
+pub fn file_cases(folder: &str, day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(config::get().paths.dir(folder)) else {
+        return vec![];
+    };
+
+    let prefix = format!("{day}-");
+    let mut cases: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter_map(|name| {
+            let case = name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            (!case.is_empty()).then(|| case.to_string())
+        })
+        .collect();
+
+    cases.sort();
+    cases
+}
+
+fn case_file_name(day: Day, case: &str) -> String {
+    if case.is_empty() {
+        format!("{day}.txt")
+    } else {
+        format!("{day}-{case}.txt")
+    }
+}
+
+fn try_read_file(folder: &str, day: Day) -> io::Result<String> {
+    try_read_file_named(folder, &case_file_name(day, ""))
+}
+
+fn try_read_file_named(folder: &str, name: &str) -> io::Result<String> {
+    encryption::read_to_string(&config::get().paths.day_file(folder, name))
+}
+
+/// Where the input of a day binary is read from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/examples`, selected with `--example`.
+    Example,
+    /// The given file, selected with `--input <path>`.
+    File(String),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse the `--example` and `--input` arguments passed to `solve`.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        if args.iter().any(|x| x == "--example") {
+            return Self::Example;
+        }
+
+        match args
+            .iter()
+            .position(|x| x == "--input")
+            .and_then(|i| args.get(i + 1))
+        {
+            Some(path) if path == "-" => Self::Stdin,
+            Some(path) => Self::File(path.clone()),
+            None => Self::Puzzle,
+        }
+    }
+
+    /// The arguments that select this source, used to forward it to a day binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example => vec!["--example".into()],
+            Self::File(path) => vec!["--input".into(), path.clone()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Reads the input of `day` from this source.
+    #[must_use]
+    pub fn read(&self, day: Day) -> String {
+        self.try_read(day).expect("could not open input file")
+    }
+
+    /// Reads the input of `day` from this source, returns an error if it cannot be read.
+    pub fn try_read(&self, day: Day) -> io::Result<String> {
+        match self {
+            Self::Puzzle => try_read_file("inputs", day),
+            Self::Example => try_read_file("examples", day),
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads the accepted answer of a part from `data/answers/{day}.txt`.
+///
+/// The file holds the answer of part one on its first line and the one of part two on its
+/// second line. Missing files and empty lines mean the answer has not been recorded.
+#[must_use]
+pub fn read_answer(day: Day, part: u8) -> Option<String> {
+    let filepath = config::get().paths.day_file("answers", format!("{day}.txt"));
+    let answers = fs::read_to_string(filepath).ok()?;
+
+    answers
+        .lines()
+        .nth(usize::from(part).checked_sub(1)?)
+        .map(str::trim)
+        .filter(|answer| !answer.is_empty())
+        .map(ToString::to_string)
+}
+
+/// Records the accepted answer of a part in `data/answers/{day}.txt`, keeping the other part.
+pub fn write_answer(day: Day, part: u8, answer: &str) -> io::Result<PathBuf> {
+    let filepath = config::get().paths.day_file("answers", format!("{day}.txt"));
+    let answers = match fs::read_to_string(&filepath) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        result => result?,
+    };
+
+    if let Some(parent) = filepath.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&filepath, with_answer(&answers, part, answer))?;
+    Ok(filepath)
+}
+
+/// The content of an answers file with the line of `part` replaced by `answer`.
+fn with_answer(answers: &str, part: u8, answer: &str) -> String {
+    let index = usize::from(part.max(1) - 1);
+    let mut lines: Vec<&str> = answers.lines().collect();
+
+    if lines.len() <= index {
+        lines.resize(index + 1, "");
+    }
+    lines[index] = answer.trim();
+
+    lines.join("\n") + "\n"
+}
+
+/// Whether the answers of a day have been recorded, see [`read_answer`].
+/// Day 25 only has one puzzle, so its part one is enough.
+#[must_use]
+pub fn is_solved(day: Day) -> bool {
+    read_answer(day, 1).is_some() && (day == 25 || read_answer(day, 2).is_some())
+}
+
+/// Generates a test per part that runs it against several example files and compares the expected answers.
+/// A case `"a"` reads `data/examples/{day}-a.txt`, the empty case reads `data/examples/{day}.txt`.
+///
+/// ```ignore
+/// advent_of_code::example_tests! {
+///     test_part_one: part_one => { "" => Some(11), "a" => Some(3) },
+///     test_part_two: part_two => { "2" => Some(31) },
+/// }
+/// ```
+///
+/// An additional `examples_are_tested` test fails if `data/examples` holds a case of the day that none of the tests lists.
+#[macro_export]
+macro_rules! example_tests {
+    ($($name:ident: $func:expr => { $($case:literal => $expected:expr),+ $(,)? }),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    let input = $crate::template::read_file_case("examples", DAY, $case);
+                    assert_eq!(($func)(&input), $expected, "example `{}`", $case);
+                )+
+            }
+        )+
+
+        #[test]
+        fn examples_are_tested() {
+            let tested: &[&str] = &[$($($case),+),+];
+            for case in $crate::template::file_cases("examples", DAY) {
+                assert!(tested.contains(&case.as_str()), "example `{case}` is not tested");
+            }
+        }
+    };
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// With a second argument, the given type implementing [`Solution`] is used instead of the
+/// `part_one` / `part_two` functions, and its parse step is timed separately.
+///
+/// Besides `main`, the macro generates `run_day`, which `all` uses to run the day in-process.
 #[macro_export]
 macro_rules! solution {
+    (@main) => {
+        // unused when the day is compiled into the registry of the `all` binary.
+        #[allow(dead_code)]
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = advent_of_code::template::InputSource::from_args().read(DAY);
+            // Hate windows
+            let input = input.replace("\r", "");
+            // parts run on watchdog threads, which need to own their input.
+            let input: &'static str = Box::leak(input.into_boxed_str());
+            run_day(input, &RunOptions::from_args(), &mut emit_report);
+        }
+    };
+    ($day:expr, $solution:ty) => {
+        /// The current day.
+        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+
+        /// Runs the day in-process, see [`advent_of_code::template::runner::RunDay`].
+        pub fn run_day(
+            input: &'static str,
+            options: &advent_of_code::template::runner::RunOptions,
+            on_report: &mut dyn FnMut(&advent_of_code::template::runner::PartReport),
+        ) {
+            advent_of_code::template::runner::run_solution::<$solution>(
+                input, DAY, options, on_report,
+            );
+        }
+
+        $crate::solution!(@main);
+    };
     ($day:expr) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
-        fn main() {
-            use advent_of_code::template::runner::*;
-            // Hate windows
-            let input = advent_of_code::template::read_file("inputs", DAY).replace("\r", "");
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+        /// Runs the day in-process, see [`advent_of_code::template::runner::RunDay`].
+        pub fn run_day(
+            input: &'static str,
+            options: &advent_of_code::template::runner::RunOptions,
+            on_report: &mut dyn FnMut(&advent_of_code::template::runner::PartReport),
+        ) {
+            use advent_of_code::template::runner::run_part;
+            if options.runs_part(1) {
+                on_report(&run_part(part_one, input, DAY, 1, options));
+            }
+            if options.runs_part(2) {
+                on_report(&run_part(part_two, input, DAY, 2, options));
+            }
         }
+
+        $crate::solution!(@main);
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{case_file_name, parse_duration, parse_timeout, timeout_arg, with_answer};
+    use crate::day;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("0.5s"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn replaces_answers() {
+        assert_eq!(with_answer("", 1, "11"), "11\n");
+        assert_eq!(with_answer("", 2, "31"), "\n31\n");
+        assert_eq!(with_answer("11\n", 2, "31\n"), "11\n31\n");
+        assert_eq!(with_answer("12\n31\n", 1, "11"), "11\n31\n");
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(parse_timeout("10s"), Ok(Some(Duration::from_secs(10))));
+        assert_eq!(parse_timeout("none"), Ok(None));
+        assert_eq!(parse_timeout("0"), Ok(None));
+        assert!(parse_timeout("never").is_err());
+
+        for timeout in [None, Some(Duration::from_millis(500))] {
+            assert_eq!(parse_timeout(&timeout_arg(timeout)), Ok(timeout));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("ten").is_err());
+        assert!(parse_duration("10h").is_err());
+    }
+
+    #[test]
+    fn names_case_files() {
+        assert_eq!(case_file_name(day!(3), ""), "03.txt");
+        assert_eq!(case_file_name(day!(3), "2"), "03-2.txt");
+        assert_eq!(case_file_name(day!(3), "a"), "03-a.txt");
+    }
+}
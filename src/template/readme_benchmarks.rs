@@ -1,7 +1,10 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io, time::Duration};
 
+use serde::{Deserialize, Serialize};
+
+use crate::template::{alloc::format_bytes, config};
 use crate::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -18,14 +21,45 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+/// The benchmark of a single part: the median duration and its spread (standard deviation).
+/// With the `alloc_stats` feature, the peak memory of the part is shown next to it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub median_nanos: f64,
+    pub std_dev_nanos: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+}
+
+impl Display for PartTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (median, std_dev) = (
+            Duration::from_nanos(self.median_nanos as u64),
+            Duration::from_nanos(self.std_dev_nanos as u64),
+        );
+
+        if self.std_dev_nanos > 0_f64 {
+            write!(f, "{median:.1?} ± {std_dev:.1?}")?;
+        } else {
+            write!(f, "{median:.1?}")?;
+        }
+
+        match self.peak_bytes {
+            Some(peak_bytes) => write!(f, " · {}", format_bytes(peak_bytes)),
+            None => Ok(()),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
@@ -75,8 +109,8 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_1.map_or_else(|| "-".into(), |t| t.to_string()),
+            timing.part_2.map_or_else(|| "-".into(), |t| t.to_string())
         ));
     }
 
@@ -94,8 +128,30 @@ fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> R
     Ok(())
 }
 
+/// Reads the benchmarks of a day back from the table: the timings of part one and part two as displayed,
+/// `-` for parts that were not benchmarked.
+pub fn read_table(readme: &str) -> Vec<(Day, String, String)> {
+    let Ok(positions) = locate_table(readme) else {
+        return vec![];
+    };
+
+    readme[positions.pos_start..positions.pos_end]
+        .lines()
+        .filter_map(|line| {
+            let day = line.strip_prefix("| [Day ")?.split(']').next()?.parse().ok()?;
+            let cells: Vec<&str> = line.split('|').map(|cell| cell.trim().trim_matches('`')).collect();
+            Some((day, cells.get(2)?.to_string(), cells.get(3)?.to_string()))
+        })
+        .collect()
+}
+
+pub fn read() -> Result<Vec<(Day, String, String)>, Error> {
+    let readme = fs::read_to_string(&config::get().paths.readme)?;
+    Ok(read_table(&readme))
+}
+
 pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
+    let path = &config::get().paths.readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     update_content(&mut readme, timings, total_millis)?;
     fs::write(path, &readme)?;
@@ -104,27 +160,35 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use super::{read_table, update_content, PartTiming, Timings, MARKER};
     use crate::day;
 
+    fn ms(median: f64, std_dev: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            median_nanos: median * 1e6,
+            std_dev_nanos: std_dev * 1e6,
+            peak_bytes: None,
+        })
+    }
+
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
+                part_1: ms(10.0, 0.0),
+                part_2: ms(20.0, 0.5),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
+                part_1: ms(30.0, 0.0),
+                part_2: ms(40.0, 0.0),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
+                part_1: ms(40.0, 1.25),
+                part_2: ms(50.0, 0.0),
                 total_nanos: 9e+10,
             },
         ]
@@ -172,9 +236,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms ± 500.0µs` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms ± 1.2ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +247,16 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn reads_benchmarks() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let table = read_table(&s);
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[0], (day!(1), "10.0ms".into(), "20.0ms ± 500.0µs".into()));
+        assert_eq!(table[2].0, day!(4));
+        assert_eq!(read_table("# readme"), vec![]);
+    }
 }
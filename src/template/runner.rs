@@ -1,64 +1,542 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    alloc::{self, AllocStats},
+    aoc_cli::{self, SubmitVerdict},
+    aoc_client, config, parse_timeout, read_answer, write_answer,
+    submissions::{Ledger, Verdict},
+    InputSource, OutputFormat, Solution, ANSI_ITALIC, ANSI_RESET,
+};
 use crate::Day;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, panic, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options of a run. Day binaries read them from the arguments passed by `solve`,
+/// the in-process runner of `all` sets them directly.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once, see [`run_timed`].
+    pub is_timed: bool,
+    /// Abandon a part if its first execution takes longer.
+    pub timeout: Option<Duration>,
+    /// Compare answers with the ones recorded in `data/answers`.
+    pub check_answers: bool,
+    /// Print intermediate results while a part is running.
+    pub show_progress: bool,
+    /// Only run this part, both parts run if absent.
+    pub part: Option<u8>,
+}
+
+impl RunOptions {
+    /// Parse the arguments passed to `solve`.
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self {
+            is_timed: env::args().any(|x| x == "--time"),
+            timeout: timeout(),
+            // recorded answers only apply to the puzzle input.
+            check_answers: InputSource::from_args() == InputSource::Puzzle,
+            show_progress: output_format() == OutputFormat::Text,
+            part: selected_part(),
+        }
+    }
+
+    /// Whether `part` should run, see [`RunOptions::part`].
+    #[must_use]
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+/// Runs every step of a day against its input and passes each report to the callback as soon as
+/// it is available. The `solution!` macro generates one of these for every day as `run_day`.
+pub type RunDay = fn(&'static str, &RunOptions, &mut dyn FnMut(&PartReport));
+
+/// A scaffolded day, compiled into the `all` binary so that it can run the day in-process.
+#[derive(Clone, Copy)]
+pub struct DaySolution {
+    pub day: Day,
+    pub run: RunDay,
+}
+
+/// Prints a report in the format passed to `solve` and submits its answer if requested.
+/// This is the callback used by the `main` function of the day binaries.
+pub fn emit_report(report: &PartReport) {
+    match output_format() {
+        OutputFormat::Text => print_report(report),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+
+    let is_puzzle_input = InputSource::from_args() == InputSource::Puzzle;
+
+    if let (Some(answer), Some(day), true) =
+        (&report.answer, Day::new(report.day), is_puzzle_input)
+    {
+        submit_result(answer, day, report.part);
+    }
+}
+
+pub fn run_part<I, T>(
+    func: impl Fn(I) -> Option<T> + Send + 'static,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+{
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let outcome = run_timed(func, input, options, |result| {
+        if options.show_progress {
+            print_result(result, &part_str, None, "");
+            if options.is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
+
+    match outcome {
+        Outcome::Finished(result, stats) => {
+            let report = PartReport::new(day, part, result.as_ref(), stats);
+            if options.check_answers {
+                report.with_expected(read_answer(day, part))
+            } else {
+                report
+            }
+        }
+        Outcome::TimedOut(limit) => PartReport::timed_out(day, part, limit),
+        Outcome::Panicked(panic) => PartReport::panicked(day, part, panic),
+    }
+}
+
+/// Parses the input of a [`Solution`] once, then runs both parts against the parsed value.
+/// The parse step is timed and reported on its own row.
+pub fn run_solution<S: Solution + 'static>(
+    input: &'static str,
+    day: Day,
+    options: &RunOptions,
+    on_report: &mut dyn FnMut(&PartReport),
+) {
+    let outcome = run_timed(S::parse, input, options, |_| {
+        if options.show_progress {
+            print_parse(None);
+            if options.is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (report, parsed) = match outcome {
+        Outcome::Finished(parsed, stats) => (PartReport::parse_step(day, stats), Some(parsed)),
+        Outcome::TimedOut(limit) => (PartReport::timed_out(day, 0, limit), None),
+        Outcome::Panicked(panic) => (PartReport::panicked(day, 0, panic), None),
+    };
+
+    on_report(&report);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    // the parts run on watchdog threads that may outlive this function, see `run_timed`.
+    // they share the parsed value, which is dropped once the last of them finishes.
+    if let Some(parsed) = parsed {
+        let parsed = Arc::new(parsed);
+        if options.runs_part(1) {
+            let part_one = |parsed: Arc<S::Parsed>| S::part_one(&parsed);
+            on_report(&run_part(part_one, Arc::clone(&parsed), day, 1, options));
+        }
+        if options.runs_part(2) {
+            let part_two = |parsed: Arc<S::Parsed>| S::part_two(&parsed);
+            on_report(&run_part(part_two, parsed, day, 2, options));
+        }
     }
 }
 
+/// The outcome of running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    /// The part returned an answer and no answer is recorded for it.
+    Solved,
+    /// The part returned `None` and no answer is recorded for it.
+    Unsolved,
+    /// The part returned the answer recorded in `data/answers`.
+    Correct,
+    /// The part did not return the answer recorded in `data/answers`.
+    Incorrect,
+    /// The input was parsed by a [`Solution`], see [`PartReport::parse_step`].
+    Parsed,
+    /// The part did not finish within the `--timeout` limit.
+    TimedOut,
+    /// The part panicked, see [`PartReport::error`].
+    Panicked,
+}
+
+/// A machine-readable record of a solution part, emitted by the day binaries with `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartReport {
+    pub day: u8,
+    /// `1` or `2`, or `0` for the parse step of a [`Solution`].
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// The accepted answer recorded in `data/answers`, if any.
+    #[serde(default)]
+    pub expected: Option<String>,
+    /// The panic message and its location if the part panicked.
+    #[serde(default)]
+    pub error: Option<String>,
+    pub stats: BenchStats,
+}
+
+impl PartReport {
+    #[must_use]
+    pub fn new<T: Display>(day: Day, part: u8, answer: Option<&T>, stats: BenchStats) -> Self {
+        Self {
+            day: day.into_inner(),
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: answer.map(ToString::to_string),
+            expected: None,
+            error: None,
+            stats,
+        }
+    }
+
+    /// Checks the answer against the recorded one and updates the status accordingly.
+    #[must_use]
+    pub fn with_expected(self, expected: Option<String>) -> Self {
+        let Some(expected) = expected else {
+            return self;
+        };
+
+        Self {
+            status: if self.answer.as_ref() == Some(&expected) {
+                PartStatus::Correct
+            } else {
+                PartStatus::Incorrect
+            },
+            expected: Some(expected),
+            ..self
+        }
+    }
+
+    /// The record of the parse step of a [`Solution`].
+    #[must_use]
+    pub fn parse_step(day: Day, stats: BenchStats) -> Self {
+        Self {
+            day: day.into_inner(),
+            part: 0,
+            status: PartStatus::Parsed,
+            answer: None,
+            expected: None,
+            error: None,
+            stats,
+        }
+    }
+
+    /// The record of a part (or parse step) that did not finish within `limit`.
+    #[must_use]
+    pub fn timed_out(day: Day, part: u8, limit: Duration) -> Self {
+        Self {
+            day: day.into_inner(),
+            part,
+            status: PartStatus::TimedOut,
+            answer: None,
+            expected: None,
+            error: None,
+            stats: BenchStats::single(limit),
+        }
+    }
+
+    /// The record of a part (or parse step) that panicked.
+    #[must_use]
+    pub fn panicked(day: Day, part: u8, error: String) -> Self {
+        Self {
+            day: day.into_inner(),
+            part,
+            status: PartStatus::Panicked,
+            answer: None,
+            expected: None,
+            error: Some(error),
+            stats: BenchStats::single(Duration::ZERO),
+        }
+    }
+
+    /// Serializes the report to a single line of JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("part reports are always serializable")
+    }
+
+    /// Parses a report from a line of JSON, returns [`None`] if the line is not a report.
+    #[must_use]
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line).ok()
+    }
+}
+
+/// Summary statistics of the samples collected while running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub samples: u128,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+    #[serde(rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Duration,
+    /// Allocations of the first run, only tracked with the `alloc_stats` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+}
+
+/// (De)serializes a [`Duration`] as an integer amount of nanoseconds.
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+impl BenchStats {
+    /// Statistics for a part that was executed exactly once.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            samples: 1,
+            min: duration,
+            median: duration,
+            p95: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            alloc: None,
+        }
+    }
+
+    /// Computes the statistics of a non-empty set of samples.
+    #[must_use]
+    pub fn from_samples(timers: &[Duration]) -> Self {
+        let mut sorted = timers.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+        // nearest-rank percentile.
+        let p95 = sorted[(len * 95).div_ceil(100).saturating_sub(1)];
+
+        #[allow(clippy::cast_precision_loss)]
+        let std_dev = {
+            let mean = average_duration(&sorted) as f64;
+            let variance = sorted
+                .iter()
+                .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+                .sum::<f64>()
+                / len as f64;
+            Duration::from_secs_f64(variance.sqrt() / 1e9)
+        };
+
+        Self {
+            samples: len as u128,
+            min: sorted[0],
+            median,
+            p95,
+            max: sorted[len - 1],
+            std_dev,
+            alloc: None,
+        }
+    }
+}
+
+/// The result of a solution part run by [`run_timed`].
+enum Outcome<T> {
+    Finished(T, BenchStats),
+    /// The first execution did not finish within the given limit.
+    TimedOut(Duration),
+    /// The part panicked, holds the panic message and its location.
+    Panicked(String),
+}
+
+/// Progress messages sent by the watchdog thread of [`run_timed`].
+enum Progress<T> {
+    Ran(T, Duration, Option<AllocStats>),
+    Benched(BenchStats),
+    Panicked(String),
+}
+
+/// Name of the threads spawned by [`run_timed`], their panics are recorded instead of printed.
+const WORKER_THREAD: &str = "aoc-part";
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that records `<message> at <location>` of panics on worker threads.
+/// Panics on any other thread are handled by the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(WORKER_THREAD) {
+                previous(info);
+                return;
+            }
+
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".into());
+
+            let description = match info.location() {
+                Some(location) => format!("{message} at {location}"),
+                None => message,
+            };
+
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(description));
+        }));
+    });
+}
+
+/// Runs `func` on the current worker thread, returns the recorded panic if it panics.
+fn catch_panic<R>(func: impl FnOnce() -> R) -> Result<R, String> {
+    panic::catch_unwind(AssertUnwindSafe(func)).map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "unknown panic".into())
+    })
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///
+/// The part runs on a separate thread. If `--timeout` is passed and the first execution exceeds it,
+/// the thread is abandoned (it is killed once the process exits) and [`Outcome::TimedOut`] is returned.
+/// Panics are caught and returned as [`Outcome::Panicked`], so the other part still runs.
+fn run_timed<I, T>(
+    func: impl Fn(I) -> T + Send + 'static,
     input: I,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> Outcome<T>
+where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    install_panic_hook();
 
-    hook(&result);
+    let is_timed = options.is_timed;
+    let (tx, rx) = mpsc::channel();
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    thread::Builder::new()
+        .name(WORKER_THREAD.into())
+        .spawn(move || {
+            // clone outside of the measured closure, the input is not allocated by the part.
+            let first_input = input.clone();
+            let ((result, base_time), alloc) = match catch_panic(|| {
+                alloc::measure(|| {
+                    let timer = Instant::now();
+                    let result = func(first_input);
+                    (result, timer.elapsed())
+                })
+            }) {
+                Ok(measured) => measured,
+                Err(panic) => {
+                    let _ = tx.send(Progress::Panicked(panic));
+                    return;
+                }
+            };
+
+            if tx.send(Progress::Ran(result, base_time, alloc)).is_ok() && is_timed {
+                let _ = tx.send(match catch_panic(|| bench(&func, input, &base_time)) {
+                    Ok(stats) => Progress::Benched(stats),
+                    Err(panic) => Progress::Panicked(panic),
+                });
+            }
+        })
+        .expect("failed to spawn worker thread");
+
+    let limit = options.timeout;
+
+    let received = match limit {
+        Some(limit) => rx.recv_timeout(limit),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
     };
 
-    (result, run.0, run.1)
-}
+    let (result, base_time, alloc) = match received {
+        Ok(Progress::Ran(result, base_time, alloc)) => (result, base_time, alloc),
+        Ok(Progress::Panicked(panic)) => return Outcome::Panicked(panic),
+        // only reachable when a limit is set.
+        Err(RecvTimeoutError::Timeout) => return Outcome::TimedOut(limit.unwrap_or_default()),
+        Ok(Progress::Benched(_)) | Err(RecvTimeoutError::Disconnected) => {
+            unreachable!("the worker always reports its first run")
+        }
+    };
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+    hook(&result);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    if !is_timed {
+        let stats = BenchStats {
+            alloc,
+            ..BenchStats::single(base_time)
+        };
+        return Outcome::Finished(result, stats);
+    }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
+    match rx.recv() {
+        Ok(Progress::Benched(stats)) => Outcome::Finished(result, BenchStats { alloc, ..stats }),
+        Ok(Progress::Panicked(panic)) => Outcome::Panicked(panic),
+        Ok(Progress::Ran(..)) | Err(_) => unreachable!("the worker always reports its benchmark"),
+    }
+}
+
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    let settings = &config::get().bench;
+    let bench_iterations = (settings.target.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(
+        u128::from(settings.min_samples),
+        u128::from(settings.max_samples),
     );
 
+    // warm up caches and branch predictors before collecting samples.
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
+
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -69,11 +547,7 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -84,61 +558,171 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+fn format_duration(stats: &BenchStats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.median)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} ± {:.1?} @ {} samples)",
+            stats.median, stats.std_dev, stats.samples
+        )
+    }
+}
+
+fn format_spread(stats: &BenchStats) -> String {
+    format!(
+        "  {ANSI_ITALIC}min {:.1?} · p95 {:.1?} · max {:.1?}{ANSI_RESET}",
+        stats.min, stats.p95, stats.max
+    )
+}
+
+/// Prints the lines below a final result: the spread of the samples and the allocations, if known.
+fn print_details(stats: &BenchStats) {
+    if stats.samples > 1 {
+        println!("{}", format_spread(stats));
+    }
+    if let Some(alloc) = stats.alloc {
+        println!("  {ANSI_ITALIC}{alloc}{ANSI_RESET}");
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+/// Prints a report received from a day binary the same way `run_part` prints it in text mode.
+pub fn print_report(report: &PartReport) {
+    let label = match report.part {
+        0 => "Parse".to_string(),
+        part => format!("Part {part}"),
+    };
+
+    if report.status == PartStatus::TimedOut {
+        print!("\r");
+        println!("{label}: ⏱ timed out ({:.1?})", report.stats.median);
+        return;
+    }
+
+    if report.status == PartStatus::Panicked {
+        print!("\r");
+        println!(
+            "{label}: ✖ panicked: {}",
+            report.error.as_deref().unwrap_or_default()
+        );
+        return;
+    }
+
+    if report.status == PartStatus::Parsed {
+        print_parse(Some(&report.stats));
+        return;
+    }
+
+    let check = match (report.status, &report.expected) {
+        (PartStatus::Correct, _) => " ✔".to_string(),
+        (PartStatus::Incorrect, Some(expected)) => {
+            format!(" ✖ expected {ANSI_BOLD}{expected}{ANSI_RESET}")
+        }
+        _ => String::new(),
+    };
+
+    print_result(&report.answer, &label, Some(&report.stats), &check);
+}
+
+/// Prints the parse step of a [`Solution`], see [`print_result`] for the behavior without `stats`.
+fn print_parse(stats: Option<&BenchStats>) {
+    match stats {
+        None => print!("Parse:"),
+        Some(stats) => {
+            print!("\r");
+            println!("Parse:{}", format_duration(stats));
+            print_details(stats);
+        }
+    }
+}
+
+/// Prints the result of a part. Without `stats`, an intermediate result is printed on the current line,
+/// which is then overwritten once the final result and its timings are known.
+/// `check` is appended to the final result, see [`print_report`].
+fn print_result<T: Display>(
+    result: &Option<T>,
+    part: &str,
+    stats: Option<&BenchStats>,
+    check: &str,
+) {
+    let duration_str = stats.map(format_duration).unwrap_or_default();
 
     match result {
         Some(result) => {
             if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                let str = format!("{part}: ▼ {duration_str}{check}");
+                match stats {
+                    None => print!("{str}"),
+                    Some(stats) => {
+                        print!("\r");
+                        println!("{str}");
+                        print_details(stats);
+                        println!("{result}");
+                    }
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}{check}");
+                match stats {
+                    None => print!("{str}"),
+                    Some(stats) => {
+                        print!("\r");
+                        println!("{str}");
+                        print_details(stats);
+                    }
                 }
             }
         }
         None => {
-            if is_intermediate_result {
+            if stats.is_none() {
                 print!("{part}: ✖");
             } else {
                 print!("\r");
-                println!("{part}: ✖             ");
+                println!("{part}: ✖{check}             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+/// Parse the `--format` argument passed to `solve`, defaults to the format in `aoc.toml`.
+fn output_format() -> OutputFormat {
+    let args: Vec<String> = env::args().collect();
+
+    args.iter()
+        .position(|x| x == "--format")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(config::get().defaults.format)
+}
+
+/// Parse the `--timeout` argument passed to `solve`, defaults to the timeout in `aoc.toml`.
+/// A part runs without time limit if neither is set, or with `--timeout none`.
+fn timeout() -> Option<Duration> {
+    let args: Vec<String> = env::args().collect();
+
+    args.iter()
+        .position(|x| x == "--timeout")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| parse_timeout(x).ok())
+        .unwrap_or(config::get().defaults.timeout)
+}
+
+/// Parse the `--part` argument passed to `solve`, both parts run if absent.
+fn selected_part() -> Option<u8> {
+    let args: Vec<String> = env::args().collect();
+
+    args.iter()
+        .position(|x| x == "--part")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| x.parse().ok())
+}
+
+/// Parse the arguments passed to `solve` and submit one part of the solution if requested.
+/// Submits through [`aoc_client`], or aoc-cli when no session cookie is set but aoc-cli is installed.
+fn submit_result<T: Display>(result: T, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -154,14 +738,148 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let answer = result.to_string();
+
+    // json output keeps stdout to one record per part, so the submission is reported on stderr.
+    let is_json = output_format() == OutputFormat::Json;
+    let say = |message: &str| {
+        if is_json {
+            eprintln!("{message}");
+        } else {
+            println!("{message}");
+        }
+    };
+
+    let mut ledger = Ledger::load(day).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    if let Err(refusal) = ledger.check(part, &answer) {
+        eprintln!("Not submitting {answer}: {refusal}.");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let (verdict, text) = if aoc_client::session().is_none() && aoc_cli::check().is_ok() {
+        say("Submitting result via aoc-cli...");
+        match aoc_cli::submit(day, part, &answer) {
+            Ok(reply) => (Some(reply.result), reply.text),
+            Err(e) => {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        say("Submitting result...");
+        match aoc_client::submit(day, part, &answer) {
+            Ok(reply) => (SubmitVerdict::parse(&reply), reply),
+            Err(e) => {
+                eprintln!("failed to submit: {e}");
+                process::exit(1);
+            }
+        }
+    };
+
+    let Some(verdict) = verdict else {
+        say(&text);
+        return;
+    };
+    say(&verdict.summary());
+
+    if verdict == SubmitVerdict::Correct {
+        match write_answer(day, part, &answer) {
+            Ok(path) => say(&format!("Recorded the answer in \"{}\".", path.display())),
+            Err(e) => eprintln!("Failed to record the answer: {e}"),
+        }
+    }
+
+    if let Some(verdict) = Verdict::from_submit(&verdict) {
+        ledger.record(part, &answer, verdict);
+        if let Err(e) = ledger.save() {
+            eprintln!("{e}");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_timed, BenchStats, Outcome, PartReport, PartStatus, RunOptions};
+    use crate::day;
+    use std::time::Duration;
+
+    fn report(answer: Option<u32>) -> PartReport {
+        PartReport::new(day!(1), 1, answer.as_ref(), BenchStats::single(Duration::ZERO))
+    }
+
+    #[test]
+    fn status_without_recorded_answer() {
+        assert_eq!(report(Some(11)).with_expected(None).status, PartStatus::Solved);
+        assert_eq!(report(None).with_expected(None).status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn status_with_recorded_answer() {
+        let expected = || Some("11".to_string());
+        assert_eq!(report(Some(11)).with_expected(expected()).status, PartStatus::Correct);
+        assert_eq!(report(Some(12)).with_expected(expected()).status, PartStatus::Incorrect);
+        assert_eq!(report(None).with_expected(expected()).status, PartStatus::Incorrect);
+    }
+
+    #[test]
+    fn runs_selected_part() {
+        let both = RunOptions::default();
+        assert!(both.runs_part(1) && both.runs_part(2));
+
+        let second = RunOptions {
+            part: Some(2),
+            ..RunOptions::default()
+        };
+        assert!(!second.runs_part(1) && second.runs_part(2));
+    }
+
+    #[test]
+    fn catches_panics() {
+        let options = RunOptions::default();
+        let outcome = run_timed(|x: u32| -> u32 { panic!("boom {x}") }, 7, &options, |_| {});
+        match outcome {
+            Outcome::Panicked(panic) => {
+                assert!(panic.starts_with("boom 7 at src/template/runner.rs:"));
+            }
+            _ => panic!("expected the part to panic"),
+        }
+    }
+
+    #[test]
+    fn stats_of_single_sample() {
+        let stats = BenchStats::from_samples(&[Duration::from_nanos(42)]);
+        assert_eq!(stats, BenchStats::single(Duration::from_nanos(42)));
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let mut timers: Vec<Duration> = (1..=100).map(Duration::from_nanos).collect();
+        timers.reverse();
+
+        let stats = BenchStats::from_samples(&timers);
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.std_dev, Duration::from_nanos(29));
+    }
+
+    #[test]
+    fn spread_ignores_outlier() {
+        let mut timers = vec![Duration::from_micros(10); 99];
+        timers.push(Duration::from_secs(1));
+
+        let stats = BenchStats::from_samples(&timers);
+        assert_eq!(stats.median, Duration::from_micros(10));
+        assert_eq!(stats.p95, Duration::from_micros(10));
+        assert_eq!(stats.max, Duration::from_secs(1));
+    }
 }
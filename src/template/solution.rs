@@ -0,0 +1,20 @@
+/// A solution that parses its input once and shares the parsed value between both parts.
+///
+/// Implementors are wired up with the `solution!(DAY, Type)` macro, the runner then
+/// reports the parse step and each part as separate rows.
+///
+/// The bounds allow the runner to execute each step on a watchdog thread.
+pub trait Solution {
+    /// The value produced from the puzzle input.
+    type Parsed: Send + Sync + 'static;
+    /// The answer of the first part.
+    type AnswerOne: std::fmt::Display + Send + 'static;
+    /// The answer of the second part.
+    type AnswerTwo: std::fmt::Display + Send + 'static;
+
+    fn parse(input: &str) -> Self::Parsed;
+
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::AnswerOne>;
+
+    fn part_two(parsed: &Self::Parsed) -> Option<Self::AnswerTwo>;
+}
@@ -0,0 +1,316 @@
+/// Module that keeps a history of the answers submitted for each day in `data/submissions/{day}.json`,
+/// so answers the site already rejected are not submitted again.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{
+    aoc_cli::{Hint, SubmitVerdict},
+    config, read_answer,
+};
+use crate::Day;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(serde_json::Error),
+    IO(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Parser(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "failed to parse submissions: {e}"),
+            Error::IO(e) => write!(f, "failed to access submissions: {e}"),
+        }
+    }
+}
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not judged, the site asks to wait before submitting again.
+    RateLimited,
+}
+
+impl Verdict {
+    /// The verdict to record for a reply of the site, `None` if the reply did not judge the answer,
+    /// e.g. because the part was already solved.
+    #[must_use]
+    pub fn from_submit(verdict: &SubmitVerdict) -> Option<Self> {
+        match verdict {
+            SubmitVerdict::Correct => Some(Verdict::Correct),
+            SubmitVerdict::Incorrect { hint: None } => Some(Verdict::Wrong),
+            SubmitVerdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => Some(Verdict::TooHigh),
+            SubmitVerdict::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => Some(Verdict::TooLow),
+            SubmitVerdict::RateLimited { .. } => Some(Verdict::RateLimited),
+            SubmitVerdict::AlreadySolved | SubmitVerdict::NotUnlocked => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate-limited",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    Duplicate(Verdict),
+    /// The answer is on the wrong side of an answer the site called too high or too low.
+    OutOfBounds {
+        bound: String,
+        verdict: Verdict,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "the part is already solved with {answer}"),
+            Refusal::Duplicate(verdict) => {
+                write!(f, "the answer was already submitted and was {verdict}")
+            }
+            Refusal::OutOfBounds { bound, verdict } => {
+                let direction = if *verdict == Verdict::TooHigh {
+                    "below"
+                } else {
+                    "above"
+                };
+                write!(
+                    f,
+                    "the answer is not {direction} {bound}, which was {verdict}"
+                )
+            }
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path_for_day(day: Day) -> PathBuf {
+    config::get()
+        .paths
+        .day_file("submissions", format!("{day}.json"))
+}
+
+/// The submissions of one day, in the order they were made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    pub day: Day,
+    pub submissions: Vec<Submission>,
+    /// The answers of both parts recorded in `data/answers`, see [`read_answer`].
+    pub answers: [Option<String>; 2],
+}
+
+impl Ledger {
+    /// Reads the submissions of `day`, a day without a file has none.
+    pub fn load(day: Day) -> Result<Self, Error> {
+        let submissions = match fs::read_to_string(get_path_for_day(day)) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            day,
+            submissions,
+            answers: [read_answer(day, 1), read_answer(day, 2)],
+        })
+    }
+
+    pub fn save(&self) -> Result<PathBuf, Error> {
+        let path = get_path_for_day(self.day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(&self.submissions)?)?;
+        Ok(path)
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.as_secs());
+
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_string(),
+            timestamp,
+            verdict,
+        });
+    }
+
+    /// The accepted answer of a part, from this ledger or `data/answers`.
+    #[must_use]
+    pub fn solution(&self, part: u8) -> Option<String> {
+        self.submissions
+            .iter()
+            .find(|s| s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.clone())
+            .or_else(|| {
+                let index = usize::from(part).checked_sub(1)?;
+                self.answers.get(index)?.clone()
+            })
+    }
+
+    /// Whether `answer` is worth submitting for `part`, given what the site said about earlier answers.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        if let Some(solution) = self.solution(part) {
+            return Err(Refusal::AlreadySolved(solution));
+        }
+
+        let judged = self
+            .submissions
+            .iter()
+            .filter(|s| s.part == part && s.verdict != Verdict::RateLimited);
+
+        if let Some(previous) = judged.clone().find(|s| s.answer == answer) {
+            return Err(Refusal::Duplicate(previous.verdict));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        for previous in judged {
+            let Ok(bound) = previous.answer.parse::<i128>() else {
+                continue;
+            };
+            let is_out_of_bounds = match previous.verdict {
+                Verdict::TooHigh => value >= bound,
+                Verdict::TooLow => value <= bound,
+                _ => false,
+            };
+            if is_out_of_bounds {
+                return Err(Refusal::OutOfBounds {
+                    bound: previous.answer.clone(),
+                    verdict: previous.verdict,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ledger, Refusal, Submission, Verdict};
+    use crate::day;
+
+    fn ledger(submissions: &[(u8, &str, Verdict)]) -> Ledger {
+        let mut ledger = Ledger {
+            day: day!(24),
+            submissions: vec![],
+            answers: [None, None],
+        };
+        for (part, answer, verdict) in submissions {
+            ledger.record(*part, answer, *verdict);
+        }
+        ledger
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let ledger = ledger(&[
+            (1, "100", Verdict::TooHigh),
+            (1, "10", Verdict::TooLow),
+            (1, "42", Verdict::Wrong),
+            (1, "50", Verdict::RateLimited),
+            (2, "7", Verdict::Correct),
+        ]);
+
+        assert_eq!(
+            ledger.check(1, "42"),
+            Err(Refusal::Duplicate(Verdict::Wrong))
+        );
+        assert!(matches!(
+            ledger.check(1, "100"),
+            Err(Refusal::Duplicate(Verdict::TooHigh))
+        ));
+        assert_eq!(
+            ledger.check(1, "150"),
+            Err(Refusal::OutOfBounds {
+                bound: "100".into(),
+                verdict: Verdict::TooHigh
+            })
+        );
+        assert!(matches!(
+            ledger.check(1, "3"),
+            Err(Refusal::OutOfBounds { .. })
+        ));
+        assert_eq!(ledger.check(1, "50"), Ok(()));
+        assert_eq!(ledger.check(1, "43"), Ok(()));
+        assert_eq!(ledger.check(1, "abc"), Ok(()));
+        assert_eq!(
+            ledger.check(2, "8"),
+            Err(Refusal::AlreadySolved("7".into()))
+        );
+    }
+
+    #[test]
+    fn refuses_recorded_answers() {
+        let mut ledger = ledger(&[(1, "42", Verdict::Wrong)]);
+        ledger.answers = [Some("43".into()), None];
+
+        assert_eq!(
+            ledger.check(1, "44"),
+            Err(Refusal::AlreadySolved("43".into()))
+        );
+        assert_eq!(ledger.check(2, "44"), Ok(()));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let ledger = ledger(&[(1, "100", Verdict::TooHigh)]);
+        let json = serde_json::to_string(&ledger.submissions).unwrap();
+
+        assert!(json.contains("\"verdict\":\"too_high\""));
+        assert_eq!(
+            serde_json::from_str::<Vec<Submission>>(&json).unwrap(),
+            ledger.submissions
+        );
+    }
+}
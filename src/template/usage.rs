@@ -0,0 +1,298 @@
+/// Describes the commands of the main binary and their flags, used for `--help` and shell completions.
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// A flag of a command, with the placeholder of its value if it takes one.
+pub struct Flag {
+    pub name: &'static str,
+    pub value: Option<&'static str>,
+    /// Fixed values the flag accepts, offered by the shell completions.
+    pub choices: &'static [&'static str],
+    pub help: &'static str,
+}
+
+pub struct CommandUsage {
+    /// Name of the command as passed to the main binary.
+    pub name: &'static str,
+    /// The cargo alias that runs the command, see `.cargo/config.toml`.
+    pub alias: &'static str,
+    /// Positional arguments, e.g. `<DAY>`.
+    pub args: &'static str,
+    pub about: &'static str,
+    pub flags: &'static [Flag],
+}
+
+const fn switch(name: &'static str, help: &'static str) -> Flag {
+    Flag {
+        name,
+        value: None,
+        choices: &[],
+        help,
+    }
+}
+
+const fn option(name: &'static str, value: &'static str, help: &'static str) -> Flag {
+    Flag {
+        name,
+        value: Some(value),
+        choices: &[],
+        help,
+    }
+}
+
+const fn choice(
+    name: &'static str,
+    value: &'static str,
+    choices: &'static [&'static str],
+    help: &'static str,
+) -> Flag {
+    Flag {
+        name,
+        value: Some(value),
+        choices,
+        help,
+    }
+}
+
+const FORMAT: Flag = choice(
+    "--format",
+    "<text|json>",
+    &["text", "json"],
+    "Print results as text or as json lines, defaults to text or aoc.toml",
+);
+const TIMEOUT: Flag = option(
+    "--timeout",
+    "<DURATION>",
+    "Abandon a part whose first run takes longer, e.g. 10s or 500ms, none turns off aoc.toml",
+);
+
+pub const COMMANDS: &[CommandUsage] = &[
+    CommandUsage {
+        name: "all",
+        alias: "all",
+        args: "[DAYS]",
+        about: "Run the solutions of all days, or of the selected DAYS such as 1-10 or 3,5,17. `cargo time` benchmarks them.",
+        flags: &[
+            switch("--release", "Update the README benchmarks when timing every day"),
+            switch("--time", "Benchmark each part instead of running it once"),
+            FORMAT,
+            TIMEOUT,
+            option("--jobs", "<N>", "Run N days in parallel, ignored with --time"),
+            switch("--solved", "Only run days with recorded answers"),
+            switch("--unsolved", "Only run days without recorded answers"),
+            option("--save-baseline", "<NAME>", "Save the timings of the run days into a baseline"),
+            option("--baseline", "<NAME>", "Compare the timings with a saved baseline"),
+            option("--threshold", "<PERCENT>", "Slowdown reported as a regression, defaults to 10 or aoc.toml"),
+        ],
+    },
+    CommandUsage {
+        name: "download",
+        alias: "download",
+        args: "<DAY>",
+        about: "Download the input and puzzle description of a day, and record the answers of its solved parts.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "read",
+        alias: "read",
+        args: "<DAY>",
+        about: "Read the puzzle description of a day in the terminal.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "extract-examples",
+        alias: "extract-examples",
+        args: "<DAY>",
+        about: "Write the examples of a downloaded puzzle to the example files and their answers to the tests.",
+        flags: &[
+            switch("--list", "Print the numbered code blocks of the puzzle"),
+            option("--block", "<N>", "Use code block N as the example of part one"),
+            option("--part-two-block", "<N>", "Use code block N as the example of part two"),
+            switch("--force", "Overwrite example files and tests that were already filled in"),
+        ],
+    },
+    CommandUsage {
+        name: "scaffold",
+        alias: "scaffold",
+        args: "<DAY>",
+        about: "Create the solution, input and example files of a day.",
+        flags: &[
+            choice(
+                "--template",
+                "<NAME>",
+                &["default", "grid", "graph", "blocks", "regex-lines"],
+                "Start from a built-in template or one in templates/",
+            ),
+            option("--answer-type", "<TYPE>", "Return type of the parts, defaults to u32 or aoc.toml"),
+            option("--examples", "<CASES>", "Also create example files for CASES such as 1,2 or a,b and test every case"),
+        ],
+    },
+    CommandUsage {
+        name: "solve",
+        alias: "solve",
+        args: "<DAY>",
+        about: "Run the solution of a day.",
+        flags: &[
+            switch("--release", "Build the day in release mode"),
+            switch("--time", "Benchmark each part instead of running it once"),
+            choice("--submit", "<1|2>", &["1", "2"], "Submit the answer of a part to the website"),
+            choice("--part", "<1|2>", &["1", "2"], "Only run one part"),
+            FORMAT,
+            TIMEOUT,
+            switch("--example", "Read the example instead of the puzzle input"),
+            option("--input", "<PATH|->", "Read the input from a file, or stdin with -"),
+        ],
+    },
+    CommandUsage {
+        name: "status",
+        alias: "status",
+        args: "",
+        about: "Show which files, answers and benchmarks exist for every day.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "test",
+        alias: "aoc-test",
+        args: "[DAYS]",
+        about: "Run the example tests of all days, or of the selected DAYS, and print a summary.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "watch-day",
+        alias: "watch-day",
+        args: "<DAY>",
+        about: "Re-run the tests and the solution of a day whenever its files change.",
+        flags: &[
+            switch("--tests", "Only run the tests"),
+            switch("--solve", "Only run the solution"),
+            switch("--release", "Build the day in release mode"),
+            switch("--example", "Solve the example instead of the puzzle input"),
+            option("--interval", "<DURATION>", "How often to check for changes, defaults to 500ms or aoc.toml"),
+        ],
+    },
+    CommandUsage {
+        name: "completions",
+        alias: "completions",
+        args: "<bash|zsh|fish>",
+        about: "Print the completion script of a shell for the advent_of_code binary.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "encrypt-inputs",
+        alias: "encrypt-inputs",
+        args: "",
+        about: "Encrypt the inputs and puzzle descriptions to .enc files that can be committed.",
+        flags: &[],
+    },
+    CommandUsage {
+        name: "decrypt-inputs",
+        alias: "decrypt-inputs",
+        args: "",
+        about: "Decrypt the committed .enc files of the inputs and puzzle descriptions.",
+        flags: &[switch("--force", "Overwrite plaintext files that differ from the encrypted ones")],
+    },
+    CommandUsage {
+        name: "check-inputs",
+        alias: "check-inputs",
+        args: "",
+        about: "Fail if plaintext inputs or puzzle descriptions are staged for the next commit.",
+        flags: &[switch("--install-hook", "Run the check before every commit as a git pre-commit hook")],
+    },
+];
+
+#[must_use]
+pub fn find(name: &str) -> Option<&'static CommandUsage> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+/// The list of commands, printed by `--help` and for an unknown command.
+#[must_use]
+pub fn overview() -> String {
+    let width = COMMANDS.iter().map(|c| c.alias.len()).max().unwrap_or(0);
+
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Usage:{ANSI_RESET} cargo <COMMAND> [OPTIONS]"),
+        String::new(),
+        format!("{ANSI_BOLD}Commands:{ANSI_RESET}"),
+    ];
+
+    lines.extend(
+        COMMANDS
+            .iter()
+            .map(|command| format!("  {:<width$}  {}", command.alias, command.about)),
+    );
+
+    lines.push(String::new());
+    lines.push("Run `cargo <COMMAND> --help` for the options of a command.".into());
+    lines.join("\n")
+}
+
+/// The usage of a single command and its flags.
+#[must_use]
+pub fn command_usage(command: &CommandUsage) -> String {
+    let args = if command.args.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", command.args)
+    };
+    let options = if command.flags.is_empty() {
+        ""
+    } else {
+        " [OPTIONS]"
+    };
+
+    let mut lines = vec![
+        format!(
+            "{ANSI_BOLD}Usage:{ANSI_RESET} cargo {}{args}{options}",
+            command.alias
+        ),
+        String::new(),
+        command.about.to_string(),
+    ];
+
+    if !command.flags.is_empty() {
+        let label = |flag: &Flag| match flag.value {
+            Some(value) => format!("{} {value}", flag.name),
+            None => flag.name.to_string(),
+        };
+        let width = command
+            .flags
+            .iter()
+            .map(|f| label(f).len())
+            .max()
+            .unwrap_or(0);
+
+        lines.push(String::new());
+        lines.push(format!("{ANSI_BOLD}Options:{ANSI_RESET}"));
+        lines.extend(
+            command
+                .flags
+                .iter()
+                .map(|flag| format!("  {:<width$}  {}", label(flag), flag.help)),
+        );
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{command_usage, find, overview, COMMANDS};
+
+    #[test]
+    fn lists_every_command() {
+        let overview = overview();
+        for command in COMMANDS {
+            assert!(overview.contains(command.alias), "{}", command.name);
+        }
+    }
+
+    #[test]
+    fn describes_flags() {
+        let usage = command_usage(find("solve").unwrap());
+
+        assert!(usage.contains("cargo solve <DAY> [OPTIONS]"));
+        assert!(usage.contains("--submit <1|2>"));
+        assert!(usage.contains("--input <PATH|->"));
+        assert!(find("unknown").is_none());
+    }
+}